@@ -16,6 +16,7 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(PanOrbitCameraPlugin)
+        .add_plugins(BevyTridentPlugin)
                 .add_systems(Startup, setup)
         .add_systems(Update, update_view)
         .run();
@@ -79,6 +80,7 @@ fn setup(
             material: materials.add(StandardMaterial::default()),
             ..Default::default()
         },
+        BevyTridentLabels::default(),
     ));
     commands.spawn((
         MaterialMeshBundle {
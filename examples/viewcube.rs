@@ -14,7 +14,7 @@ fn main() {
         .add_plugins(DefaultPickingPlugins)
         // bevy_ui debug bug(https://github.com/aevyrie/bevy_mod_picking/issues/317), use default to disable debug ui
         .insert_resource(DebugPickingMode::Normal)
-        .add_plugins(BevyViewCubePlugin{use_powerful_viewcube:true})
+        .add_plugins(BevyViewCubePlugin{use_powerful_viewcube:true, ..default()})
         .add_systems(Startup, setup)
         .run();
 }
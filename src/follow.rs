@@ -0,0 +1,74 @@
+use bevy::{
+    prelude::*,
+    transform::{
+        helper::TransformHelper,
+        TransformSystem,
+    },
+};
+
+/// Keeps an entity at origin of `target`, despawned when target is gone.
+#[derive(Component)]
+pub(crate) struct FollowTarget {
+    pub target: Entity,
+    /// Align with rotation of target, otherwise world axes.
+    pub local: bool,
+}
+
+/// Moves [`FollowTarget`] entities, added by gizmo and trident plugins, whichever comes first.
+pub(crate) struct FollowTargetPlugin;
+
+impl Plugin for FollowTargetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, follow_target.before(TransformSystem::TransformPropagate));
+    }
+}
+
+/// Transform helper reads every `Transform`, so followers are moved after targets are resolved.
+#[allow(clippy::type_complexity)]
+pub(crate) fn follow_target(
+    mut commands: Commands,
+    mut params: ParamSet<(TransformHelper, Query<(Entity, &FollowTarget, &mut Transform)>)>,
+) {
+    let targets = params.p1().iter().map(|(entity, follow, _)| (entity, follow.target)).collect::<Vec<_>>();
+    let targets = targets.into_iter()
+        .map(|(entity, target)| (entity, params.p0().compute_global_transform(target).ok()))
+        .collect::<Vec<_>>();
+    let mut followers = params.p1();
+    for (entity, target) in targets {
+        let Some(target) = target else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let Ok((_, follow, mut transform)) = followers.get_mut(entity) else {
+            continue;
+        };
+        let (_, rotation, translation) = target.to_scale_rotation_translation();
+        transform.translation = translation;
+        transform.rotation = if follow.local { rotation } else { Quat::IDENTITY };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_target_and_despawns_with_it() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, FollowTargetPlugin));
+        let rotation = Quat::from_rotation_y(0.5);
+        let target = app.world.spawn(TransformBundle::from_transform(
+            Transform::from_xyz(1.0, 2.0, 3.0).with_rotation(rotation)
+        )).id();
+        let local = app.world.spawn((TransformBundle::default(), FollowTarget { target, local: true })).id();
+        let world = app.world.spawn((TransformBundle::default(), FollowTarget { target, local: false })).id();
+        app.update();
+        let transform = |app: &App, entity| *app.world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform(&app, local).translation, Vec3::new(1.0, 2.0, 3.0));
+        assert!(transform(&app, local).rotation.abs_diff_eq(rotation, 1e-6));
+        assert_eq!(transform(&app, world).rotation, Quat::IDENTITY);
+        app.world.despawn(target);
+        app.update();
+        assert!(app.world.get_entity(local).is_none() && app.world.get_entity(world).is_none());
+    }
+}
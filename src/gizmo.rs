@@ -0,0 +1,299 @@
+mod rotate;
+mod scale;
+mod translate;
+
+use bevy::prelude::*;
+use bevy_mod_picking::{
+    pointer::{InputPress, PressDirection},
+    prelude::*,
+};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+pub use rotate::{GizmoRotated, RotateGizmo};
+pub use scale::ScaleGizmo;
+pub use translate::TranslateGizmo;
+
+/// Systems of transform gizmos, [`TranslateGizmo`], [`RotateGizmo`] and [`ScaleGizmo`].
+/// Needs `DefaultPickingPlugins`.
+pub struct BevyTridentGizmoPlugin;
+
+impl Plugin for BevyTridentGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::follow::FollowTargetPlugin>() {
+            app.add_plugins(crate::follow::FollowTargetPlugin);
+        }
+        app
+        .init_resource::<GizmoDrag>()
+        .add_event::<GizmoRotated>()
+        .add_systems(Update, (
+            translate::spawn_translate_gizmo,
+            translate::despawn_translate_gizmo,
+            rotate::spawn_rotate_gizmo,
+            rotate::despawn_rotate_gizmo,
+            scale::spawn_scale_gizmo,
+            scale::despawn_scale_gizmo,
+            grab_handle,
+            drag_handle.after(grab_handle),
+            release_handle.after(drag_handle),
+        ));
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GizmoKind {
+    Translate,
+    Rotate,
+    Scale,
+}
+
+/// Gizmo placed at `target`, handles are its children.
+#[derive(Component)]
+pub(crate) struct GizmoRoot {
+    pub target: Entity,
+    pub kind: GizmoKind,
+}
+
+/// Draggable part of a gizmo, directions are in local space of gizmo root.
+#[derive(Component, Clone, Copy)]
+pub(crate) enum GizmoHandle {
+    /// Move along direction.
+    Axis(Vec3),
+    /// Move in plane with normal.
+    Plane(Vec3),
+    /// Rotate about normal.
+    Ring(Vec3),
+    /// Scale local axis of target with index, dragged along that axis, value is length of handle.
+    Scale(usize, f32),
+    /// Scale all axes by dragging up or down, value is size of gizmo.
+    UniformScale(f32),
+}
+
+/// Handle being dragged and camera paused meanwhile.
+#[derive(Resource, Default)]
+pub(crate) struct GizmoDrag {
+    camera: Option<Entity>,
+    orbit_enabled: bool,
+    /// Rotation dragged so far, without snapping.
+    angle: f32,
+    /// Rotation applied so far, with snapping.
+    applied: f32,
+}
+
+pub(crate) fn despawn_roots(
+    commands: &mut Commands,
+    roots: &Query<(Entity, &GizmoRoot)>,
+    target: Entity,
+    kind: GizmoKind,
+) {
+    for (entity, root) in roots.iter() {
+        if root.target == target && root.kind == kind {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn grab_handle(
+    mut downs: EventReader<Pointer<Down>>,
+    mut drag: ResMut<GizmoDrag>,
+    handles: Query<(), With<GizmoHandle>>,
+    mut orbit_cameras: Query<&mut PanOrbitCamera>,
+) {
+    for down in downs.read() {
+        if !handles.contains(down.target) || drag.camera.is_some() {
+            continue;
+        }
+        drag.camera = Some(down.hit.camera);
+        drag.angle = 0.0;
+        drag.applied = 0.0;
+        // Stop camera orbiting while dragging handle.
+        if let Ok(mut orbit) = orbit_cameras.get_mut(down.hit.camera) {
+            drag.orbit_enabled = orbit.enabled;
+            orbit.enabled = false;
+        }
+    }
+}
+
+fn release_handle(
+    mut presses: EventReader<InputPress>,
+    mut drag: ResMut<GizmoDrag>,
+    mut orbit_cameras: Query<&mut PanOrbitCamera>,
+) {
+    // Read all presses, an unread release would end the next drag at once.
+    if presses.read().filter(|press| press.direction == PressDirection::Up).count() == 0 {
+        return;
+    }
+    let Some(camera) = drag.camera.take() else {
+        return;
+    };
+    if let Ok(mut orbit) = orbit_cameras.get_mut(camera) {
+        orbit.enabled = drag.orbit_enabled;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn drag_handle(
+    mut drags: EventReader<Pointer<Drag>>,
+    mut rotated: EventWriter<GizmoRotated>,
+    mut drag: ResMut<GizmoDrag>,
+    handles: Query<(&GizmoHandle, &Parent)>,
+    roots: Query<&GizmoRoot>,
+    cameras: Query<&Camera>,
+    transforms: Query<&GlobalTransform>,
+    mut targets: Query<(&mut Transform, Option<&Parent>, Option<&RotateGizmo>)>,
+) {
+    let Some(camera_entity) = drag.camera else {
+        return;
+    };
+    let (Ok(camera), Ok(camera_transform)) = (cameras.get(camera_entity), transforms.get(camera_entity)) else {
+        return;
+    };
+    let origin = camera.logical_viewport_rect().map_or(Vec2::ZERO, |rect| rect.min);
+    for event in drags.read() {
+        let Ok((handle, parent)) = handles.get(event.target) else {
+            continue;
+        };
+        let (Ok(root), Ok(root_transform)) = (roots.get(parent.get()), transforms.get(parent.get())) else {
+            continue;
+        };
+        let position = event.pointer_location.position - origin;
+        let rays = (
+            camera.viewport_to_world(camera_transform, position - event.delta),
+            camera.viewport_to_world(camera_transform, position),
+        );
+        let (Some(previous), Some(current)) = rays else {
+            continue;
+        };
+        let center = root_transform.translation();
+        let world = |dir: Vec3| root_transform.affine().transform_vector3(dir).normalize();
+
+        let parent_transform = targets.get(root.target).ok()
+            .and_then(|(_, parent, _)| parent)
+            .and_then(|parent| transforms.get(parent.get()).ok())
+            .copied();
+        let Ok((mut transform, _, rotate)) = targets.get_mut(root.target) else {
+            continue;
+        };
+        // Deltas are in world space, target moves in space of its parent.
+        let to_parent = |vector: Vec3| match parent_transform {
+            Some(parent) => parent.affine().inverse().transform_vector3(vector),
+            None => vector,
+        };
+        match *handle {
+            GizmoHandle::Axis(dir) => {
+                let axis = world(dir);
+                if let (Some(a), Some(b)) = (ray_line(previous, center, axis), ray_line(current, center, axis)) {
+                    transform.translation += to_parent(axis * (b - a));
+                }
+            },
+            GizmoHandle::Plane(normal) => {
+                let normal = world(normal);
+                if let (Some(a), Some(b)) = (ray_plane(previous, center, normal), ray_plane(current, center, normal)) {
+                    transform.translation += to_parent(b - a);
+                }
+            },
+            GizmoHandle::Ring(normal) => {
+                let normal = world(normal);
+                let (Some(a), Some(b)) = (ray_plane(previous, center, normal), ray_plane(current, center, normal)) else {
+                    continue;
+                };
+                let (a, b) = (a - center, b - center);
+                drag.angle += normal.dot(a.cross(b)).atan2(a.dot(b));
+                let snapped = match rotate.and_then(|rotate| rotate.snap) {
+                    Some(step) if step > 0.0 => (drag.angle / step).round() * step,
+                    _ => drag.angle,
+                };
+                let angle = snapped - drag.applied;
+                if angle == 0.0 {
+                    continue;
+                }
+                drag.applied = snapped;
+                transform.rotate(Quat::from_axis_angle(to_parent(normal).normalize(), angle));
+                rotated.send(GizmoRotated {
+                    target: root.target,
+                    axis: normal,
+                    angle,
+                });
+            },
+            GizmoHandle::Scale(axis, length) => {
+                let Ok(target) = transforms.get(root.target) else {
+                    continue;
+                };
+                let length = root_transform.affine().transform_vector3(Vec3::X * length).length();
+                if let Some(factor) = drag_scale(target, axis, length, previous, current) {
+                    transform.scale[axis] *= factor;
+                }
+            },
+            GizmoHandle::UniformScale(size) => {
+                let normal = camera_transform.forward();
+                if let (Some(a), Some(b)) = (ray_plane(previous, center, normal), ray_plane(current, center, normal)) {
+                    let factor = 1.0 + (b - a).dot(camera_transform.up()) / size;
+                    if factor > 0.0 {
+                        transform.scale *= factor;
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Factor of local axis `axis` of target dragged from `previous` to `current` ray,
+/// measured along that axis in world space, so rotated or skewed parents scale no other axis.
+/// Handle spans `length` in world space.
+pub(crate) fn drag_scale(target: &GlobalTransform, axis: usize, length: f32, previous: Ray3d, current: Ray3d) -> Option<f32> {
+    let direction = target.affine().transform_vector3(Vec3::AXES[axis]).try_normalize()?;
+    let center = target.translation();
+    let (a, b) = (ray_line(previous, center, direction)?, ray_line(current, center, direction)?);
+    let factor = 1.0 + (b - a) / length;
+    (factor > 0.0).then_some(factor)
+}
+
+/// Parameter of the point on line nearest to ray.
+pub(crate) fn ray_line(ray: Ray3d, point: Vec3, dir: Vec3) -> Option<f32> {
+    let w = point - ray.origin;
+    let b = dir.dot(*ray.direction);
+    let denominator = 1.0 - b * b;
+    // Looking along the line.
+    if denominator.abs() < 1e-4 {
+        return None;
+    }
+    Some((b * ray.direction.dot(w) - dir.dot(w)) / denominator)
+}
+
+/// Intersection of ray and plane.
+pub(crate) fn ray_plane(ray: Ray3d, point: Vec3, normal: Vec3) -> Option<Vec3> {
+    let denominator = normal.dot(*ray.direction);
+    // Looking along the plane.
+    if denominator.abs() < 1e-4 {
+        return None;
+    }
+    let distance = normal.dot(point - ray.origin) / denominator;
+    Some(ray.get_point(distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_drag_follows_local_axis_under_rotated_parent() {
+        let parent = Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4))
+            .with_scale(Vec3::new(2.0, 1.0, 1.0));
+        let mut child = Transform::from_rotation(Quat::from_rotation_z(0.5)).with_translation(Vec3::new(1.0, 0.0, 0.0));
+        let global = |child: &Transform| GlobalTransform::from(parent) * GlobalTransform::from(*child);
+        let before = global(&child);
+        // Local X is neither a world axis nor orthogonal to local Y under this parent.
+        let x = before.affine().transform_vector3(Vec3::X).normalize();
+        let eye = Vec3::new(0.0, 0.0, 10.0);
+        let ray = |at: f32| Ray3d::new(eye, before.translation() + x * at - eye);
+        let factor = drag_scale(&before, 0, 1.0, ray(1.0), ray(1.5)).unwrap();
+        assert!((factor - 1.5).abs() < 1e-4, "factor {factor}");
+        child.scale[0] *= factor;
+        let after = global(&child);
+        let column = |transform: &GlobalTransform, axis: usize| transform.affine().transform_vector3(Vec3::AXES[axis]);
+        assert!(column(&after, 0).abs_diff_eq(column(&before, 0) * factor, 1e-4));
+        for axis in [1, 2] {
+            assert!(column(&after, axis).abs_diff_eq(column(&before, axis), 1e-5), "axis {axis} changed");
+        }
+        assert!(drag_scale(&before, 0, 1.0, ray(1.0), ray(-0.5)).is_none(), "flipped scale");
+    }
+}
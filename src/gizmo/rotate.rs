@@ -0,0 +1,106 @@
+use bevy::{
+    prelude::*,
+    math::primitives::Torus,
+};
+use bevy_mod_picking::prelude::*;
+
+use crate::{follow::FollowTarget, trident::BevyTridentAxis};
+
+use super::{
+    despawn_roots,
+    GizmoHandle,
+    GizmoKind,
+    GizmoRoot,
+};
+
+/// Add to an entity to rotate it by dragging rings around a trident.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RotateGizmo {
+    /// Ring normals and colors.
+    pub axis: BevyTridentAxis,
+    pub radius: f32,
+    pub thickness: f32,
+    /// Rotation snaps to multiples of this angle in radians.
+    pub snap: Option<f32>,
+    /// Rotate about local axes of entity, otherwise world axes.
+    pub local: bool,
+}
+
+impl Default for RotateGizmo {
+    fn default() -> Self {
+        Self {
+            axis: BevyTridentAxis::default(),
+            radius: 0.8,
+            thickness: 0.02,
+            snap: None,
+            local: false,
+        }
+    }
+}
+
+/// Sent for each rotation applied by a [`RotateGizmo`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct GizmoRotated {
+    pub target: Entity,
+    /// World space axis of rotation.
+    pub axis: Vec3,
+    /// Angle in radians.
+    pub angle: f32,
+}
+
+pub(crate) fn spawn_rotate_gizmo(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    gizmos: Query<(Entity, &RotateGizmo), Changed<RotateGizmo>>,
+    roots: Query<(Entity, &GizmoRoot)>,
+) {
+    for (target, gizmo) in gizmos.iter() {
+        despawn_roots(&mut commands, &roots, target, GizmoKind::Rotate);
+
+        commands.spawn((
+            SpatialBundle::default(),
+            GizmoRoot {
+                target,
+                kind: GizmoKind::Rotate,
+            },
+            FollowTarget {
+                target,
+                local: gizmo.local,
+            },
+        )).with_children(|builder| {
+            for dir in 0..3 {
+                let normal = gizmo.axis.direction(dir);
+                let ring = Torus {
+                    minor_radius: gizmo.thickness,
+                    major_radius: gizmo.radius,
+                };
+                // Torus lies in XZ plane.
+                let mesh = Mesh::from(ring).rotated_by(Quat::from_rotation_arc(Vec3::Y, normal));
+                builder.spawn((
+                    MaterialMeshBundle {
+                        mesh: meshes.add(mesh),
+                        material: materials.add(StandardMaterial {
+                            base_color: gizmo.axis.color(dir),
+                            unlit: true,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    PickableBundle::default(),
+                    GizmoHandle::Ring(normal),
+                ));
+            }
+        });
+    }
+}
+
+pub(crate) fn despawn_rotate_gizmo(
+    mut commands: Commands,
+    mut removed: RemovedComponents<RotateGizmo>,
+    roots: Query<(Entity, &GizmoRoot)>,
+) {
+    for target in removed.read() {
+        despawn_roots(&mut commands, &roots, target, GizmoKind::Rotate);
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::{
+    prelude::*,
+    math::primitives::Cuboid,
+};
+use bevy_mod_picking::prelude::*;
+
+use crate::{
+    follow::FollowTarget,
+    trident::{
+        BevyTridentArrow,
+        BevyTridentAxis,
+        BevyTridentTip,
+    },
+};
+
+use super::{
+    despawn_roots,
+    GizmoHandle,
+    GizmoKind,
+    GizmoRoot,
+};
+
+/// Add to an entity to scale it by dragging cube-tipped arrows of a trident.
+/// Scale is along local axes of entity, so gizmo follows its rotation.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScaleGizmo {
+    /// Geometry and colors of handles.
+    pub axis: BevyTridentAxis,
+    /// Edge of center cube scaling all axes, no center handle when 0.
+    pub center_size: f32,
+}
+
+impl Default for ScaleGizmo {
+    fn default() -> Self {
+        Self {
+            axis: BevyTridentAxis::from_arrow(BevyTridentArrow::default().with_tip(BevyTridentTip::Cube)),
+            center_size: 0.12,
+        }
+    }
+}
+
+pub(crate) fn spawn_scale_gizmo(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    gizmos: Query<(Entity, &ScaleGizmo), Changed<ScaleGizmo>>,
+    roots: Query<(Entity, &GizmoRoot)>,
+) {
+    for (target, gizmo) in gizmos.iter() {
+        despawn_roots(&mut commands, &roots, target, GizmoKind::Scale);
+
+        let material = materials.add(StandardMaterial {
+            unlit: true,
+            ..Default::default()
+        });
+        commands.spawn((
+            SpatialBundle::default(),
+            GizmoRoot {
+                target,
+                kind: GizmoKind::Scale,
+            },
+            FollowTarget {
+                target,
+                local: true,
+            },
+        )).with_children(|builder| {
+            for dir in 0..3 {
+                builder.spawn((
+                    MaterialMeshBundle {
+                        mesh: meshes.add(gizmo.axis.arrow_mesh(dir)),
+                        material: material.clone(),
+                        ..Default::default()
+                    },
+                    PickableBundle::default(),
+                    GizmoHandle::Scale(dir, gizmo.axis.axises[dir].length()),
+                ));
+            }
+            if gizmo.center_size <= 0.0 {
+                builder.spawn(MaterialMeshBundle {
+                    mesh: meshes.add(gizmo.axis.origin_mesh()),
+                    material,
+                    ..Default::default()
+                });
+                return;
+            }
+            builder.spawn((
+                MaterialMeshBundle {
+                    mesh: meshes.add(Cuboid::from_size(Vec3::splat(gizmo.center_size))),
+                    material: materials.add(StandardMaterial {
+                        base_color: gizmo.axis.origin_color,
+                        unlit: true,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                PickableBundle::default(),
+                GizmoHandle::UniformScale(gizmo.axis.axises.iter().map(|arrow| arrow.length()).fold(0.0, f32::max)),
+            ));
+        });
+    }
+}
+
+pub(crate) fn despawn_scale_gizmo(
+    mut commands: Commands,
+    mut removed: RemovedComponents<ScaleGizmo>,
+    roots: Query<(Entity, &GizmoRoot)>,
+) {
+    for target in removed.read() {
+        despawn_roots(&mut commands, &roots, target, GizmoKind::Scale);
+    }
+}
@@ -0,0 +1,142 @@
+use bevy::{
+    prelude::*,
+    render::{
+        mesh::Indices,
+        render_asset::RenderAssetUsages,
+        render_resource::PrimitiveTopology,
+    },
+};
+use bevy_mod_picking::prelude::*;
+
+use crate::{follow::FollowTarget, trident::BevyTridentAxis};
+
+use super::{
+    despawn_roots,
+    GizmoHandle,
+    GizmoKind,
+    GizmoRoot,
+};
+
+/// Add to an entity to move it by dragging arrows of a trident.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TranslateGizmo {
+    /// Geometry and colors of arrows.
+    pub axis: BevyTridentAxis,
+    /// Squares between two arrows to move in XY, YZ and XZ plane.
+    pub plane_handles: bool,
+    /// Move along local axes of entity, otherwise world axes.
+    pub local: bool,
+}
+
+impl Default for TranslateGizmo {
+    fn default() -> Self {
+        Self {
+            axis: BevyTridentAxis::default(),
+            plane_handles: true,
+            local: false,
+        }
+    }
+}
+
+/// Square spanning the two axes other than `normal`, placed away from origin.
+fn plane_mesh(axis: &BevyTridentAxis, normal: usize) -> (Mesh, Vec3) {
+    let (a, b) = ((normal + 1) % 3, (normal + 2) % 3);
+    let length = |dir: usize| axis.axises[dir].tail_length;
+    let u = axis.direction(a) * length(a);
+    let v = axis.direction(b) * length(b);
+    let (near, far) = (0.25, 0.45);
+    let positions = vec![
+        (u * near + v * near).to_array(),
+        (u * far + v * near).to_array(),
+        (u * far + v * far).to_array(),
+        (u * near + v * far).to_array(),
+    ];
+    let normal = u.cross(v).normalize_or_zero();
+    let [ra, ga, ba, _] = axis.color(a).as_linear_rgba_f32();
+    let [rb, gb, bb, _] = axis.color(b).as_linear_rgba_f32();
+    let color = [(ra + rb) / 2.0, (ga + gb) / 2.0, (ba + bb) / 2.0, 1.0];
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    mesh.insert_indices(Indices::U32(vec![0, 1, 2, 2, 3, 0]));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vec![normal.to_array(); 4]);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0f32, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, vec![color; 4]);
+    (mesh, normal)
+}
+
+pub(crate) fn spawn_translate_gizmo(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    gizmos: Query<(Entity, &TranslateGizmo), Changed<TranslateGizmo>>,
+    roots: Query<(Entity, &GizmoRoot)>,
+) {
+    for (target, gizmo) in gizmos.iter() {
+        despawn_roots(&mut commands, &roots, target, GizmoKind::Translate);
+
+        let arrow_material = materials.add(StandardMaterial {
+            unlit: true,
+            ..Default::default()
+        });
+        let plane_material = materials.add(StandardMaterial {
+            base_color: Color::rgba(1.0, 1.0, 1.0, 0.6),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            cull_mode: None,
+            double_sided: true,
+            ..Default::default()
+        });
+        commands.spawn((
+            SpatialBundle::default(),
+            GizmoRoot {
+                target,
+                kind: GizmoKind::Translate,
+            },
+            FollowTarget {
+                target,
+                local: gizmo.local,
+            },
+        )).with_children(|builder| {
+            builder.spawn(MaterialMeshBundle {
+                mesh: meshes.add(gizmo.axis.origin_mesh()),
+                material: arrow_material.clone(),
+                ..Default::default()
+            });
+            for dir in 0..3 {
+                builder.spawn((
+                    MaterialMeshBundle {
+                        mesh: meshes.add(gizmo.axis.arrow_mesh(dir)),
+                        material: arrow_material.clone(),
+                        ..Default::default()
+                    },
+                    PickableBundle::default(),
+                    GizmoHandle::Axis(gizmo.axis.direction(dir)),
+                ));
+                if !gizmo.plane_handles {
+                    continue;
+                }
+                let (mesh, normal) = plane_mesh(&gizmo.axis, dir);
+                builder.spawn((
+                    MaterialMeshBundle {
+                        mesh: meshes.add(mesh),
+                        material: plane_material.clone(),
+                        ..Default::default()
+                    },
+                    PickableBundle::default(),
+                    GizmoHandle::Plane(normal),
+                ));
+            }
+        });
+    }
+}
+
+pub(crate) fn despawn_translate_gizmo(
+    mut commands: Commands,
+    mut removed: RemovedComponents<TranslateGizmo>,
+    roots: Query<(Entity, &GizmoRoot)>,
+) {
+    for target in removed.read() {
+        despawn_roots(&mut commands, &roots, target, GizmoKind::Translate);
+    }
+}
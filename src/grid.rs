@@ -0,0 +1,235 @@
+use bevy::{
+    prelude::*,
+    transform::TransformSystem,
+};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::trident::BevyTridentAxis;
+
+/// Draws [`BevyTridentGrid`] around the orbit camera focus with gizmo lines.
+pub struct BevyTridentGridPlugin;
+
+impl Plugin for BevyTridentGridPlugin {
+    fn build(&self, app: &mut App) {
+        app
+        .init_resource::<BevyTridentGrid>()
+        .init_gizmo_group::<GridGizmos>()
+        .add_systems(PostUpdate, draw_grid.after(TransformSystem::TransformPropagate));
+    }
+}
+
+/// Plane of grid, named by its world axes, Y is up as on viewcube.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GridPlane {
+    /// Ground, seen from Top.
+    #[default]
+    XZ,
+    /// Seen from Front.
+    XY,
+    /// Seen from Right.
+    YZ,
+}
+
+impl GridPlane {
+    /// Indices of the two world axes spanning plane.
+    pub const fn axes(&self) -> [usize; 2] {
+        match self {
+            GridPlane::XZ => [0, 2],
+            GridPlane::XY => [0, 1],
+            GridPlane::YZ => [1, 2],
+        }
+    }
+
+    /// World axis perpendicular to plane.
+    pub const fn normal(&self) -> usize {
+        match self {
+            GridPlane::XZ => 1,
+            GridPlane::XY => 2,
+            GridPlane::YZ => 0,
+        }
+    }
+}
+
+/// Reference grid through origin with major and minor lines and colored axis lines.
+#[derive(Debug, Clone, Resource)]
+pub struct BevyTridentGrid {
+    pub plane: GridPlane,
+    /// Minor spacing at `spacing` camera radius, scaled by powers of `subdivisions` as camera zooms.
+    pub spacing: f32,
+    /// Minor cells per major cell, at least 2.
+    pub subdivisions: usize,
+    /// Minor cells from center to edge, lines fade out towards edge.
+    pub cells: usize,
+    pub minor_color: Color,
+    pub major_color: Color,
+    /// Colors of X, Y and Z axis lines, same as trident by default.
+    pub axis_colors: [Color; 3],
+    /// Adapt spacing to radius of `PanOrbitCamera`, otherwise keep `spacing`.
+    pub adaptive: bool,
+    /// Camera grid follows, `None` picks the active `PanOrbitCamera` with lowest order, or origin without one.
+    pub camera: Option<Entity>,
+}
+
+impl Default for BevyTridentGrid {
+    fn default() -> Self {
+        Self {
+            plane: GridPlane::XZ,
+            spacing: 1.0,
+            subdivisions: 10,
+            cells: 40,
+            minor_color: Color::rgba(0.5, 0.5, 0.5, 0.3),
+            major_color: Color::rgba(0.6, 0.6, 0.6, 0.7),
+            axis_colors: BevyTridentAxis::COLORS,
+            adaptive: true,
+            camera: None,
+        }
+    }
+}
+
+impl BevyTridentGrid {
+    /// Minor cells per major cell as used, `subdivisions` clamped to 2.
+    fn major_every(&self) -> usize {
+        self.subdivisions.max(2)
+    }
+
+    /// Minor spacing at camera `radius` and how far it is towards next coarser spacing, from 0 to 1.
+    pub fn spacing_at(&self, radius: f32) -> (f32, f32) {
+        let base = self.major_every() as f32;
+        if !self.adaptive || radius <= 0.0 || self.spacing <= 0.0 {
+            return (self.spacing, 0.0);
+        }
+        let level = (radius / self.spacing).log(base);
+        (self.spacing * base.powf(level.floor()), level.fract().rem_euclid(1.0))
+    }
+
+    /// Color of line `index` spacings from origin, running along world axis `axis`.
+    fn line_color(&self, index: i64, axis: usize, blend: f32) -> Color {
+        let major = self.major_every() as i64;
+        if index == 0 {
+            self.axis_colors[axis]
+        } else if index % (major * major) == 0 {
+            // Stays major at next coarser level.
+            self.major_color
+        } else if index % major == 0 {
+            // Turns into minor line of next coarser level.
+            mix(self.major_color, self.minor_color, blend)
+        } else {
+            // Minor lines vanish as spacing approaches next coarser level.
+            with_alpha(self.minor_color, 1.0 - blend)
+        }
+    }
+}
+
+/// Gizmos of grid, configured apart from default gizmos.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct GridGizmos;
+
+/// Point of plane at `u` and `v` along its axes.
+fn plane_point(plane: GridPlane, u: f32, v: f32) -> Vec3 {
+    let mut point = Vec3::ZERO;
+    let [a, b] = plane.axes();
+    point[a] = u;
+    point[b] = v;
+    point
+}
+
+fn with_alpha(color: Color, alpha: f32) -> Color {
+    color.with_a(color.a() * alpha)
+}
+
+/// Color from `from` at 0 to `to` at 1.
+fn mix(from: Color, to: Color, t: f32) -> Color {
+    let (from, to) = (from.as_rgba_f32(), to.as_rgba_f32());
+    let [r, g, b, a] = [0, 1, 2, 3].map(|c| from[c] + (to[c] - from[c]) * t);
+    Color::rgba(r, g, b, a)
+}
+
+/// Pieces of a line for fading along it.
+const FADE_SEGMENTS: usize = 8;
+
+pub(crate) fn draw_grid(
+    mut gizmos: Gizmos<GridGizmos>,
+    grid: Res<BevyTridentGrid>,
+    orbits: Query<(&PanOrbitCamera, &Camera)>,
+) {
+    let orbit = match grid.camera {
+        Some(camera) => orbits.get(camera).ok(),
+        // Same camera every frame however query is ordered, e.g. main view of split screen.
+        None => orbits.iter().filter(|(_, camera)| camera.is_active).min_by_key(|(_, camera)| camera.order),
+    }.map(|(orbit, _)| orbit);
+    let (focus, radius) = orbit.map_or((Vec3::ZERO, grid.spacing), |orbit| {
+        (orbit.focus, orbit.radius.unwrap_or(orbit.target_radius))
+    });
+    let (spacing, blend) = grid.spacing_at(radius);
+    if spacing <= 0.0 || grid.cells == 0 {
+        return;
+    }
+    let [a, b] = grid.plane.axes();
+    let extent = spacing * grid.cells as f32;
+    // Center snapped to major lines so lines stay in place while panning.
+    let major = spacing * grid.major_every() as f32;
+    let center = Vec2::new((focus[a] / major).round() * major, (focus[b] / major).round() * major);
+    let fade = |point: Vec2| (1.0 - point.distance(center) / extent).clamp(0.0, 1.0).powi(2);
+    let mut line = |along_u: bool, at: f32, color: Color| {
+        let points = (0..=FADE_SEGMENTS).map(|i| {
+            let t = -extent + 2.0 * extent * i as f32 / FADE_SEGMENTS as f32;
+            let point = if along_u { Vec2::new(center.x + t, at) } else { Vec2::new(at, center.y + t) };
+            (plane_point(grid.plane, point.x, point.y), with_alpha(color, fade(point)))
+        });
+        gizmos.linestrip_gradient(points);
+    };
+    let cells = grid.cells as i64;
+    for (along_u, origin) in [(true, center.y), (false, center.x)] {
+        let first = (origin / spacing).round() as i64;
+        for i in first - cells..=first + cells {
+            let at = i as f32 * spacing;
+            // Line through origin is the world axis it runs along.
+            let axis = if along_u { a } else { b };
+            line(along_u, at, grid.line_color(i, axis, blend));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spacing_adapts_to_radius() {
+        let grid = BevyTridentGrid::default();
+        assert_eq!(grid.spacing_at(5.0).0, 1.0);
+        assert_eq!(grid.spacing_at(50.0).0, 10.0);
+        assert!((grid.spacing_at(0.5).0 - 0.1).abs() < 1e-6);
+        assert!(grid.spacing_at(9.0).1 > grid.spacing_at(2.0).1);
+        let fixed = BevyTridentGrid { adaptive: false, ..default() };
+        assert_eq!(fixed.spacing_at(500.0).0, 1.0);
+    }
+
+    #[test]
+    fn lines_are_colored_by_index() {
+        let grid = BevyTridentGrid { plane: GridPlane::XY, ..default() };
+        let [a, b] = grid.plane.axes();
+        assert_eq!(grid.line_color(0, a, 0.0), Color::RED);
+        assert_eq!(grid.line_color(0, b, 0.0), Color::GREEN);
+        for i in [-20, -10, 10, 30] {
+            assert_eq!(grid.line_color(i, a, 0.0), grid.major_color, "line {i}");
+            // Fades into minor line of next coarser level, which starts at full minor color.
+            assert_eq!(grid.line_color(i, a, 1.0), grid.line_color(i / 10, a, 0.0), "line {i}");
+        }
+        for i in [-100, 200] {
+            assert_eq!(grid.line_color(i, a, 0.7), grid.major_color, "line {i}");
+        }
+        let halfway = grid.line_color(10, a, 0.5).a();
+        assert!(grid.minor_color.a() < halfway && halfway < grid.major_color.a());
+        for i in [-11, -1, 1, 9, 15] {
+            assert_eq!(grid.line_color(i, a, 0.0), grid.minor_color, "line {i}");
+        }
+        assert_eq!(grid.line_color(3, a, 1.0).a(), 0.0);
+
+        // Too few subdivisions fall back to 2 for both spacing steps and major lines.
+        let coarse = BevyTridentGrid { subdivisions: 1, ..default() };
+        assert_eq!(coarse.spacing_at(4.5).0, 4.0);
+        assert_eq!(coarse.line_color(2, a, 0.0), coarse.major_color);
+        assert_eq!(coarse.line_color(1, a, 0.0), coarse.minor_color);
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::{
+    prelude::*,
+    transform::helper::TransformHelper,
+    ui::UiSystem,
+};
+
+/// Text kept at screen position of a point attached to `anchor`, always facing screen.
+/// Rendered with UI, spawn along with [`TextBundle`] and [`TargetCamera`] of the camera which renders anchor.
+#[derive(Component, Clone, Copy)]
+pub struct BillboardLabel {
+    /// Entity the label attached to, label despawns with it.
+    pub anchor: Entity,
+    /// Offset in local space of anchor.
+    pub offset: Vec3,
+}
+
+impl BillboardLabel {
+    /// Absolute positioned text for label.
+    pub fn text_bundle(text: impl Into<String>, color: Color, font_size: f32) -> TextBundle {
+        TextBundle::from_section(text, TextStyle {
+            font_size,
+            color,
+            ..Default::default()
+        }).with_style(Style {
+            position_type: PositionType::Absolute,
+            ..Default::default()
+        })
+    }
+}
+
+pub(crate) struct BillboardLabelPlugin;
+
+impl Plugin for BillboardLabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, update_labels.before(UiSystem::Layout));
+    }
+}
+
+pub(crate) fn update_labels(
+    mut commands: Commands,
+    mut labels: Query<(Entity, &BillboardLabel, &TargetCamera, &Node, &mut Style, &mut Visibility)>,
+    cameras: Query<&Camera>,
+    visibilities: Query<&InheritedVisibility>,
+    helper: TransformHelper,
+) {
+    for (entity, label, target, node, mut style, mut visibility) in labels.iter_mut() {
+        let Ok(anchor) = helper.compute_global_transform(label.anchor) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let position = match (cameras.get(target.0), helper.compute_global_transform(target.0)) {
+            (Ok(camera), Ok(camera_transform)) => camera.world_to_viewport(
+                &camera_transform,
+                anchor.transform_point(label.offset),
+            ),
+            _ => None,
+        };
+        let anchor_visible = visibilities.get(label.anchor).map_or(true, |v| v.get());
+        let Some(position) = position.filter(|_| anchor_visible) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Inherited);
+        // Center text on position.
+        let half = node.size() / 2.0;
+        style.left = Val::Px(position.x - half.x);
+        style.top = Val::Px(position.y - half.y);
+    }
+}
@@ -1,3 +1,9 @@
+mod follow;
+mod gizmo;
+mod grid;
+mod label;
+#[cfg(test)]
+mod mesh_validation;
 mod trident;
 mod viewcube;
 
@@ -9,6 +15,11 @@ const PI_4_3:f32 = PI / 4.0 * 3.0;
 #[derive(Component)]
 pub(crate) struct SmallView;
 
+impl SmallView {
+    /// Order of viewcube camera, drawn after scene cameras and their trident overlay cameras.
+    pub(crate) const ORDER: isize = 100;
+}
+
 /// Add this to target pan_orbit_camera.
 /// panic when not set any ViewcubeBinding
 #[derive(Component)]
@@ -25,7 +36,7 @@ pub(crate) fn create_small_view(mut commands: bevy::ecs::system::Commands) {
     commands.spawn((
         Camera3dBundle{
             camera: Camera {
-                order: 1,
+                order: SmallView::ORDER,
                 clear_color: ClearColorConfig::None,
                 ..Default::default()
             },
@@ -50,10 +61,58 @@ pub(crate) fn create_small_view(mut commands: bevy::ecs::system::Commands) {
 
 pub mod prelude {
     pub use crate::trident::{
+        AttachedTrident,
         BevyTridentArrow,
+        BevyTridentArrowField,
+        BevyTridentArrowMeshBuilder,
         BevyTridentAxis,
-        BevyTridentCone
+        BevyTridentAxisMeshBuilder,
+        BevyTridentCap,
+        BevyTridentColorMap,
+        BevyTridentCone,
+        BevyTridentConeMeshBuilder,
+        BevyTridentFieldScale,
+        BevyTridentLabels,
+        BevyTridentLines,
+        BevyTridentLod,
+        BevyTridentMeshSettings,
+        BevyTridentNegative,
+        BevyTridentPlugin,
+        BevyTridentShading,
+        BevyTridentTickLabels,
+        BevyTridentTicks,
+        BevyTridentTip,
+        BevyTridentUvLayout,
+        ConstantScreenSize,
+        draw_trident,
+        TridentOverlay,
+        TridentOverlayCamera
+    };
+    pub use crate::viewcube::{
+        BevyViewCubePlugin,
+        CubePart,
+        CustomViewcube,
+        CustomViewcubePart,
+        CustomViewcubeScene,
+        ViewcubeTarget,
+        ViewcubeHit,
+        ViewcubeCompass,
+        ViewcubeFade,
+        ViewcubeOutline
+    };
+    pub use crate::label::BillboardLabel;
+    pub use crate::gizmo::{
+        BevyTridentGizmoPlugin,
+        GizmoRotated,
+        RotateGizmo,
+        ScaleGizmo,
+        TranslateGizmo
+    };
+    pub use crate::grid::{
+        BevyTridentGrid,
+        BevyTridentGridPlugin,
+        GridGizmos,
+        GridPlane
     };
-    pub use crate::viewcube::BevyViewCubePlugin;
     pub use crate::ViewcubeBinding;
 }
\ No newline at end of file
@@ -0,0 +1,51 @@
+//! Checks shared by mesh tests.
+
+use bevy::{
+    prelude::*,
+    render::mesh::{Indices, VertexAttributeValues},
+};
+
+/// Triangles of `mesh` placed by `transform`, with vertex normals.
+pub(crate) fn triangles(mesh: &Mesh, transform: Transform) -> Vec<([Vec3; 3], [Vec3; 3])> {
+    let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+        panic!("no positions");
+    };
+    let Some(VertexAttributeValues::Float32x3(normals)) = mesh.attribute(Mesh::ATTRIBUTE_NORMAL) else {
+        panic!("no normals");
+    };
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(Indices::U32(indices)) => indices.iter().map(|i| *i as usize).collect(),
+        Some(Indices::U16(indices)) => indices.iter().map(|i| *i as usize).collect(),
+        None => panic!("no indices"),
+    };
+    assert_eq!(indices.len() % 3, 0, "incomplete triangle");
+    indices.chunks_exact(3).map(|triangle| {
+        let corner = |k: usize| {
+            assert!(triangle[k] < positions.len(), "index {} out of {} vertices", triangle[k], positions.len());
+            (
+                transform.transform_point(Vec3::from(positions[triangle[k]])),
+                transform.rotation * Vec3::from(normals[triangle[k]]),
+            )
+        };
+        let [a, b, c] = [corner(0), corner(1), corner(2)];
+        ([a.0, b.0, c.0], [a.1, b.1, c.1])
+    }).collect()
+}
+
+/// Index bounds, winding agreeing with normals, unit normals, uvs in [0, 1] and no degenerate triangles.
+pub(crate) fn assert_valid(mesh: &Mesh) {
+    for (positions, normals) in triangles(mesh, Transform::IDENTITY) {
+        let [a, b, c] = positions;
+        let face = (b - a).cross(c - a);
+        assert!(face.length() > 1e-9, "degenerate triangle {:?}", positions);
+        for normal in normals {
+            assert!((normal.length() - 1.0).abs() < 1e-4, "normal {normal} not unit length");
+            assert!(face.dot(normal) > 0.0, "triangle {:?} wound against normal {normal}", positions);
+        }
+    }
+    if let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+        for uv in uvs {
+            assert!(uv.iter().all(|c| (0.0..=1.0).contains(c)), "uv {:?} outside [0, 1]", uv);
+        }
+    }
+}
@@ -1,220 +1,505 @@
 pub mod arrow;
+mod attach;
+mod field;
+pub(crate) mod labels;
+mod lines;
+mod lod;
+mod mesh;
+mod overlay;
+mod screen_size;
+mod ticks;
 
 use bevy::{
+    app::{App, Plugin, PostUpdate, Update},
+    ecs::schedule::IntoSystemConfigs,
+    pbr::MaterialPlugin,
+    render::camera::CameraUpdateSystem,
+    transform::TransformSystem,
+    render::color::Color,
     math::{
-        primitives::Sphere, Vec2, Vec3
+        primitives::{Cuboid, Sphere}, Mat3, Quat, Vec2, Vec3
     },
     render::{
         mesh::{
-            Indices, Mesh, VertexAttributeValues
+            Indices, Mesh, Meshable, VertexAttributeValues
         }, render_asset::RenderAssetUsages, render_resource::PrimitiveTopology
     },
 };
 
 pub use arrow::{
     BevyTridentCone,
-    BevyTridentArrow
+    BevyTridentArrow,
+    BevyTridentTip
 };
+pub use attach::AttachedTrident;
+pub use field::{
+    BevyTridentArrowField,
+    BevyTridentColorMap,
+    BevyTridentFieldScale
+};
+pub use labels::{BevyTridentLabels, BevyTridentTickLabels};
+pub use lines::{draw_trident, BevyTridentLines};
+pub use lod::BevyTridentLod;
+pub use mesh::{
+    BevyTridentArrowMeshBuilder,
+    BevyTridentAxisMeshBuilder,
+    BevyTridentCap,
+    BevyTridentConeMeshBuilder,
+    BevyTridentMeshSettings,
+    BevyTridentShading,
+    BevyTridentUvLayout
+};
+pub use overlay::{TridentOverlay, TridentOverlayCamera};
+pub use screen_size::ConstantScreenSize;
+pub use ticks::BevyTridentTicks;
+
+/// Systems for trident components, e.g. [`BevyTridentLabels`], [`BevyTridentTickLabels`], [`ConstantScreenSize`], [`AttachedTrident`],
+/// [`TridentOverlay`], [`BevyTridentLod`] and [`BevyTridentLines`].
+pub struct BevyTridentPlugin;
+
+impl Plugin for BevyTridentPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<labels::TridentLabelPlugin>() {
+            app.add_plugins(labels::TridentLabelPlugin);
+        }
+        if !app.is_plugin_added::<crate::follow::FollowTargetPlugin>() {
+            app.add_plugins(crate::follow::FollowTargetPlugin);
+        }
+        app
+        .add_plugins(MaterialPlugin::<attach::OnTopMaterial>::default())
+        .add_systems(Update, (
+            attach::spawn_attached_trident,
+            attach::despawn_attached_trident,
+            overlay::spawn_overlay_camera,
+            overlay::add_overlay_layer,
+        ))
+        .add_systems(PostUpdate, (
+            screen_size::constant_screen_size
+                .after(crate::follow::follow_target)
+                .before(crate::label::update_labels),
+            lod::select_lod.after(screen_size::constant_screen_size),
+        ).before(TransformSystem::TransformPropagate))
+        .add_systems(PostUpdate, overlay::sync_overlay_camera.before(CameraUpdateSystem))
+        .add_systems(PostUpdate, lines::draw_trident_lines.after(TransformSystem::TransformPropagate));
+    }
+}
 
+/// Positions, normals, uvs, indices and colors of a part of trident.
+type MeshBuffers = (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<[f32; 2]>, Vec<u32>, Vec<[f32; 4]>);
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy)]
 pub struct BevyTridentAxis {
     pub axises: [BevyTridentArrow; 3],
+    /// Color of sphere at origin.
+    pub origin_color: Color,
+    /// Arrows along negative axes.
+    pub negative: BevyTridentNegative,
+    /// Columns are directions of X, Y and Z arrows, world axes by default.
+    pub frame: Mat3,
+    /// Graduations along shafts.
+    pub ticks: Option<BevyTridentTicks>,
+}
+
+/// How negative half axes are generated.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum BevyTridentNegative {
+    #[default]
+    None,
+    /// Shaft without cone, radius scaled from arrow, solid when `dashes` is 0.
+    Shaft { radius_scale: f32, dashes: usize },
+    /// Same arrow with color multiplied by `dim`.
+    Dimmed { dim: f32 },
+}
+
+impl Default for BevyTridentAxis {
+    fn default() -> Self {
+        Self::from_arrow(BevyTridentArrow::default())
+    }
 }
 
 impl BevyTridentAxis {
-    pub const TRIDENT_10: BevyTridentAxis = BevyTridentAxis {
-        axises: [
-            BevyTridentArrow::TRIDENT_ARROW_10,
-            BevyTridentArrow::TRIDENT_ARROW_10,
-            BevyTridentArrow::TRIDENT_ARROW_10,
-        ]
-    };
-    pub const TRIDENT_100: BevyTridentAxis = BevyTridentAxis {
-        axises: [
-            BevyTridentArrow::TRIDENT_ARROW_100,
-            BevyTridentArrow::TRIDENT_ARROW_100,
-            BevyTridentArrow::TRIDENT_ARROW_100,
-        ],
-    };
+    /// Default colors of X, Y and Z.
+    pub const COLORS: [Color; 3] = [Color::RED, Color::GREEN, Color::BLUE];
+
+    pub const TRIDENT_10: BevyTridentAxis = BevyTridentAxis::from_arrow(BevyTridentArrow::TRIDENT_ARROW_10);
+    pub const TRIDENT_100: BevyTridentAxis = BevyTridentAxis::from_arrow(BevyTridentArrow::TRIDENT_ARROW_100);
+
+    /// Same arrow on each axis, colored with [`BevyTridentAxis::COLORS`].
+    pub const fn from_arrow(mut arrow: BevyTridentArrow) -> Self {
+        arrow.color = None;
+        Self {
+            axises: [arrow; 3],
+            origin_color: Color::WHITE,
+            negative: BevyTridentNegative::None,
+            frame: Mat3::IDENTITY,
+            ticks: None,
+        }
+    }
+
+    /// Trident of a local coordinate frame, e.g. rotation of a joint.
+    pub fn from_frame(frame: impl Into<Mat3>) -> Self {
+        Self::default().with_frame(frame)
+    }
 
+    /// Arrows along given directions, need not be orthogonal.
+    pub fn from_directions(directions: [Vec3; 3]) -> Self {
+        Self::default().with_frame(Mat3::from_cols(directions[0], directions[1], directions[2]))
+    }
+
+    /// Set colors of X, Y and Z.
+    pub const fn with_colors(mut self, colors: [Color; 3]) -> Self {
+        self.axises[0].color = Some(colors[0]);
+        self.axises[1].color = Some(colors[1]);
+        self.axises[2].color = Some(colors[2]);
+        self
+    }
+
+    pub const fn with_origin_color(mut self, color: Color) -> Self {
+        self.origin_color = color;
+        self
+    }
+
+    pub const fn with_negative(mut self, negative: BevyTridentNegative) -> Self {
+        self.negative = negative;
+        self
+    }
+
+    pub const fn with_ticks(mut self, ticks: BevyTridentTicks) -> Self {
+        self.ticks = Some(ticks);
+        self
+    }
+
+    pub fn with_frame(mut self, frame: impl Into<Mat3>) -> Self {
+        self.frame = frame.into();
+        self
+    }
+
+    /// Unit direction of arrow, world axis when column of frame is zero.
+    pub fn direction(&self, dir: usize) -> Vec3 {
+        let mut axis = Vec3::ZERO;
+        axis[dir] = 1.0;
+        let direction = self.frame.col(dir).normalize_or_zero();
+        if direction == Vec3::ZERO { axis } else { direction }
+    }
+
+    /// Color of arrow, [`BevyTridentAxis::COLORS`] unless arrow sets one.
+    pub fn color(&self, dir: usize) -> Color {
+        self.axises[dir].color.unwrap_or(Self::COLORS[dir])
+    }
+
+    /// Tip of each arrow.
+    pub fn tips(&self) -> [Vec3; 3] {
+        [0, 1, 2].map(|dir| self.direction(dir) * self.axises[dir].length())
+    }
 }
 
 impl From<BevyTridentAxis> for Mesh {
     fn from(trident: BevyTridentAxis) -> Self {
-        let (x_points, x_normals, x_uvs, x_indices, x_colors) = trident.gen_axis(0);
-        let (y_points, y_normals, y_uvs, y_indices, y_colors) = trident.gen_axis(1);
-        let (z_points, z_normals, z_uvs, z_indices, z_colors) = trident.gen_axis(2);
-        let (o_points, o_normals, o_uvs, o_indices, o_colors) = trident.gen_origin(
-            x_points.len() + y_points.len() + z_points.len()
-        );
-
-        let positions = [x_points, y_points, z_points, o_points].concat();
-        let normals = [x_normals, y_normals, z_normals, o_normals].concat();
-        let uvs = [x_uvs, y_uvs, z_uvs, o_uvs].concat();
-        let indices = [x_indices, y_indices, z_indices, o_indices].concat();
-        let colors = [x_colors, y_colors, z_colors, o_colors].concat();
-
-        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
-        mesh.insert_indices(Indices::U32(indices));
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
-        mesh
+        trident.mesh().build()
     }
 }
 
-impl BevyTridentAxis {
-    fn gen_axis(&self, dir: usize)
-    -> (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<[f32; 2]>, Vec<u32>, Vec<[f32; 4]>) {
-        let d = self.axises[dir].cone.subdivisions;
-        let n_vertices = 3 * (self.axises[dir].cone.subdivisions + 1) + 2;
-        let mut positions: Vec<[f32; 3]> = Vec::with_capacity(n_vertices);
-        let mut normals: Vec<[f32; 3]> = Vec::with_capacity(n_vertices);
-        let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(n_vertices);
-        let mut indices: Vec<u32> = Vec::with_capacity(n_vertices);
-
-        positions.resize_with(n_vertices, Default::default);
-        normals.resize_with(n_vertices, Default::default);
-        uvs.resize_with(n_vertices, Default::default);
-
-        let uv_stride = match dir {
-            0 => Vec2::ZERO,
-            1 => Vec2::new(0.5, 0.0),
-            2 => Vec2::new(0.0, 0.5),
-            _ => panic!("Invalid axis"),
-        };
-
-        let cr = self.axises[dir].cone.radius;
-        let tr = self.axises[dir].tail_radius;
-        // top
-        let mut top = Vec3::ZERO;
-        top[dir] = self.axises[dir].tail_length + self.axises[dir].cone.height;
-        positions[0] = top.into();
-        normals[0] = top.normalize().into();
-        normals[1] = (-top.normalize()).into();
-        uvs[0] = (Vec2::new(0.0, 0.25) + uv_stride).into();
-        uvs[1] = (Vec2::new(0.5, 0.25) + uv_stride).into();
-
-        // Cone circular
-        let stride = 2.0 * std::f32::consts::PI / self.axises[dir].cone.subdivisions as f32;
-        (0..=self.axises[dir].cone.subdivisions).for_each(|i| {
-            let phi = i as f32 * stride;
-            let vertice = match dir {
-                0 => Vec3::new(0.0, phi.cos(), phi.sin()),
-                1 => Vec3::new(phi.sin(), 0.0, phi.cos()),
-                2 => Vec3::new(phi.cos(), phi.sin(), 0.0),
-                _ => panic!("Invalid axis"),
-            };
+/// Concatenate parts, indices of each part start from 0.
+fn merge_buffers(parts: impl IntoIterator<Item = MeshBuffers>) -> MeshBuffers {
+    let mut merged: MeshBuffers = Default::default();
+    for (positions, normals, uvs, indices, colors) in parts {
+        let shift = merged.0.len() as u32;
+        merged.0.extend(positions);
+        merged.1.extend(normals);
+        merged.2.extend(uvs);
+        merged.3.extend(indices.into_iter().map(|i| i + shift));
+        merged.4.extend(colors);
+    }
+    merged
+}
 
-            let mut cone_vertice = vertice * cr;
-            let mut converge_vertice = vertice * tr;
-            let cylinder_vertice = vertice * tr;
-            cone_vertice[dir] = self.axises[dir].tail_length;
-            converge_vertice[dir] = self.axises[dir].tail_length;
-
-            let i1 = i + 2;
-            let i2 = i + (self.axises[dir].cone.subdivisions + 1) + 2;
-            let i3 = i + 2 * (self.axises[dir].cone.subdivisions + 1) + 2;
-
-            // position
-            positions[i1] = cone_vertice.into();
-            positions[i2] = converge_vertice.into();
-            positions[i3] = cylinder_vertice.into();
-
-            // normal
-            let unit_to_top = top.normalize();
-            let unit_converge_to_cone = (cone_vertice - converge_vertice).normalize();
-            let unit_cylinder = (cylinder_vertice - converge_vertice).normalize();
-            // normal cone
-            let tmp = unit_converge_to_cone.cross(unit_to_top).normalize();
-            let cone_normal = ((top - cone_vertice).normalize()).cross(tmp).normalize();
-            let converge_normal = (unit_converge_to_cone + unit_cylinder).normalize();
-            let cylinder_normal = (unit_cylinder + cylinder_vertice.normalize()).normalize();
-            normals[i1] = cone_normal.into();
-            normals[i2] = converge_normal.into();
-            normals[i3] = cylinder_normal.into();
-
-            // uv
-            uvs[i1] = (Vec2::new(0.25, i as f32 / d as f32) / 2.0 + uv_stride).into();
-            uvs[i2] = (Vec2::new(0.50, i as f32 / d as f32) / 2.0 + uv_stride).into();
-            uvs[i3] = (Vec2::new(0.75, i as f32 / d as f32) / 2.0 + uv_stride).into();
-        });
+/// Rotate positions and normals, rotation keeps winding.
+fn rotate_buffers(buffers: &mut MeshBuffers, rotation: Quat) {
+    for position in buffers.0.iter_mut() {
+        *position = rotation.mul_vec3((*position).into()).into();
+    }
+    for normal in buffers.1.iter_mut() {
+        *normal = rotation.mul_vec3((*normal).into()).into();
+    }
+}
 
-        // indices
-        for point in 2..d + 2 {
-            let top = 0;
-            let bottom = 1;
+fn buffers_to_mesh((positions, normals, uvs, indices, colors): MeshBuffers) -> Mesh {
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    mesh.insert_indices(Indices::U32(indices));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh
+}
 
-            let top_left = point + 1;
-            let top_right = point;
+/// Cylinder along `dir` from `start` to `end`, closed unless cap is open.
+fn gen_cylinder(
+    dir: Vec3, start: f32, end: f32, radius: f32, subdivisions: usize, color: Color,
+    settings: &BevyTridentMeshSettings,
+) -> MeshBuffers {
+    let mut parts = vec![gen_tube(dir, start, end, radius, subdivisions, settings.shaft_segments, (1.0, 0.0))];
+    if settings.cap == BevyTridentCap::Closed {
+        parts.push(gen_annulus(dir, start, (0.0, subdivisions), (radius, subdivisions), -dir, (1.0, 1.0)));
+        parts.push(gen_annulus(dir, end, (0.0, subdivisions), (radius, subdivisions), dir, (0.0, 0.0)));
+    }
+    paint_buffers(merge_buffers(parts), color)
+}
 
-            let bottom_left =  2 * (d + 1) + point + 1;
-            let bottom_right = 2 * (d + 1) + point;
+/// Point on unit circle around `dir`, counterclockwise seen from tip of `dir`.
+fn ring_point(dir: Vec3, i: f32, subdivisions: usize) -> Vec3 {
+    let u = dir.any_orthonormal_vector();
+    let v = dir.cross(u);
+    let phi = i / subdivisions as f32 * std::f32::consts::TAU;
+    u * phi.cos() + v * phi.sin()
+}
 
-            indices.push(top as u32);
-            indices.push(top_right as u32);
-            indices.push(top_left as u32);
+/// Side of tube along `dir` from `start` to `end`.
+/// First uv coordinate runs along tube from `uv.0` to `uv.1`, second around it.
+fn gen_tube(
+    dir: Vec3, start: f32, end: f32, radius: f32, subdivisions: usize, segments: usize, uv: (f32, f32),
+) -> MeshBuffers {
+    let (mut positions, mut normals, mut uvs, mut indices) = (vec![], vec![], vec![], vec![]);
+    let segments = segments.max(1);
+    for segment in 0..=segments {
+        let s = segment as f32 / segments as f32;
+        let at = start + (end - start) * s;
+        for i in 0..=subdivisions {
+            let radial = ring_point(dir, i as f32, subdivisions);
+            positions.push((radial * radius + dir * at).to_array());
+            normals.push(radial.to_array());
+            uvs.push([uv.0 + (uv.1 - uv.0) * s, i as f32 / subdivisions as f32]);
+        }
+    }
+    let stride = subdivisions as u32 + 1;
+    for segment in 0..segments as u32 {
+        for i in 0..subdivisions as u32 {
+            let (lower, upper) = (segment * stride + i, (segment + 1) * stride + i);
+            indices.extend([lower, lower + 1, upper, upper, lower + 1, upper + 1]);
+        }
+    }
+    (positions, normals, uvs, indices, vec![])
+}
 
-            indices.push(bottom as u32);
-            indices.push(bottom_right as u32);
-            indices.push(bottom_left as u32);
+/// Flat ring at `at` along `dir` between radii, facing `normal`, a disc when inner radius is 0.
+/// Inner and outer edges are each a radius with its vertex count, zipped together when counts differ.
+/// First uv coordinate runs from `uv.0` at inner to `uv.1` at outer edge.
+fn gen_annulus(
+    dir: Vec3, at: f32, inner: (f32, usize), outer: (f32, usize), normal: Vec3, uv: (f32, f32),
+) -> MeshBuffers {
+    let (mut positions, mut normals, mut uvs, mut indices) = (vec![], vec![], vec![], vec![]);
+    let ((inner, n), (outer, m)) = (inner, outer);
+    let ring = |radius: f32, subdivisions: usize, u: f32| (0..=subdivisions).map(move |i| (
+        (ring_point(dir, i as f32, subdivisions) * radius + dir * at).to_array(),
+        [u, i as f32 / subdivisions as f32],
+    ));
+    if inner > 0.0 {
+        (positions, uvs) = ring(inner, n, uv.0).chain(ring(outer, m, uv.1)).unzip();
+        let (n, m) = (n as u32, m as u32);
+        let outer = |j: u32| n + 1 + j;
+        let (mut i, mut j) = (0, 0);
+        // Advance along whichever edge has the nearer next vertex.
+        while i < n || j < m {
+            if j < m && (i == n || (j + 1) * n <= (i + 1) * m) {
+                indices.extend([outer(j), outer(j + 1), i]);
+                j += 1;
+            } else {
+                indices.extend([i, outer(j), i + 1]);
+                i += 1;
+            }
         }
-        for sub in 0..d {
-            let i_cone = sub + 2;
-            let i_cone_next = i_cone + 1;
-            let i_converge = i_cone + (d + 1);
-            let i_converge_next = i_converge + 1;
-            let i_cylinder = i_cone + 2 * (d + 1);
-            let i_cylinder_next = i_cylinder + 1;
-
-            indices.push(i_cone as u32);
-            indices.push(i_converge_next as u32);
-            indices.push(i_cone_next as u32);
-
-            indices.push(i_converge as u32);
-            indices.push(i_converge_next as u32);
-            indices.push(i_cone as u32);
-
-            indices.push(i_converge as u32);
-            indices.push(i_cylinder_next as u32);
-            indices.push(i_converge_next as u32);
-
-            indices.push(i_converge as u32);
-            indices.push(i_cylinder as u32);
-            indices.push(i_cylinder_next as u32);
+    } else {
+        positions.push((dir * at).to_array());
+        uvs.push([uv.0, 0.5]);
+        ring(outer, m, uv.1).for_each(|(position, uv)| {
+            positions.push(position);
+            uvs.push(uv);
+        });
+        for i in 0..m as u32 {
+            indices.extend([0, i + 1, i + 2]);
         }
-        if dir != 0 {
-            let axis_stride = dir * n_vertices;
-            for index in indices.iter_mut() {
-                *index += axis_stride as u32;
+    }
+    // Triangles above face `dir`.
+    if normal.dot(dir) < 0.0 {
+        indices.chunks_exact_mut(3).for_each(|triangle| triangle.swap(1, 2));
+    }
+    normals.resize(positions.len(), normal.to_array());
+    (positions, normals, uvs, indices, vec![])
+}
+
+/// Side of cone along `dir` with base at `base`, apex repeated per slice.
+/// First uv coordinate is 0 at apex and 0.25 at base.
+fn gen_cone(
+    dir: Vec3, base: f32, radius: f32, height: f32, subdivisions: usize, shading: BevyTridentShading,
+) -> MeshBuffers {
+    let (mut positions, mut normals, mut uvs, mut indices) = (vec![], vec![], vec![], vec![]);
+    let apex = dir * (base + height);
+    let ring = |i: f32| ring_point(dir, i, subdivisions);
+    let slant = |radial: Vec3| (radial * height + dir * radius).normalize().to_array();
+    let around = |i: f32| i / subdivisions as f32;
+    match shading {
+        BevyTridentShading::Smooth => {
+            for i in 0..=subdivisions {
+                let radial = ring(i as f32);
+                positions.push((radial * radius + dir * base).to_array());
+                normals.push(slant(radial));
+                uvs.push([0.25, around(i as f32)]);
             }
+            for i in 0..subdivisions as u32 {
+                let top = positions.len() as u32;
+                let middle = i as f32 + 0.5;
+                positions.push(apex.to_array());
+                normals.push(slant(ring(middle)));
+                uvs.push([0.0, around(middle)]);
+                indices.extend([i, i + 1, top]);
+            }
+        },
+        BevyTridentShading::Hard => {
+            for i in 0..subdivisions {
+                let (current, next) = (ring(i as f32), ring(i as f32 + 1.0));
+                let a = current * radius + dir * base;
+                let b = next * radius + dir * base;
+                let normal = (b - a).cross(apex - a).normalize().to_array();
+                let first = positions.len() as u32;
+                positions.extend([a.to_array(), b.to_array(), apex.to_array()]);
+                normals.extend([normal; 3]);
+                uvs.extend([
+                    [0.25, around(i as f32)],
+                    [0.25, around(i as f32 + 1.0)],
+                    [0.0, around(i as f32 + 0.5)],
+                ]);
+                indices.extend([first, first + 1, first + 2]);
+            }
+        },
+    }
+    (positions, normals, uvs, indices, vec![])
+}
+
+/// Fill vertex colors of buffers with one color.
+fn paint_buffers(mut buffers: MeshBuffers, color: Color) -> MeshBuffers {
+    buffers.4 = vec![color.as_linear_rgba_f32(); buffers.0.len()];
+    buffers
+}
+
+impl BevyTridentAxis {
+    /// Arrows are generated along world axis then rotated to direction.
+    fn rotation(&self, dir: usize) -> Quat {
+        let mut axis = Vec3::ZERO;
+        axis[dir] = 1.0;
+        Quat::from_rotation_arc(axis, self.direction(dir))
+    }
+
+    /// Single positive arrow, used by parts picked separately.
+    pub(crate) fn arrow_mesh(&self, dir: usize) -> Mesh {
+        let mut buffers = self.gen_axis(dir, &BevyTridentMeshSettings::default());
+        rotate_buffers(&mut buffers, self.rotation(dir));
+        buffers_to_mesh(buffers)
+    }
+
+    pub(crate) fn origin_mesh(&self) -> Mesh {
+        buffers_to_mesh(self.gen_origin())
+    }
+
+    /// Positive arrow along world axis `dir`.
+    fn gen_axis(&self, dir: usize, settings: &BevyTridentMeshSettings) -> MeshBuffers {
+        let arrow = &self.axises[dir];
+        let mut axis = Vec3::ZERO;
+        axis[dir] = 1.0;
+        let tip_center = axis * (arrow.tail_length + arrow.cone.height / 2.0);
+        let tip = match arrow.tip {
+            BevyTridentTip::Cone => None,
+            BevyTridentTip::Cube => {
+                let mut size = Vec3::splat(2.0 * arrow.cone.radius);
+                size[dir] = arrow.cone.height;
+                Some(Mesh::from(Cuboid::from_size(size)).translated_by(tip_center))
+            },
+            BevyTridentTip::Sphere => {
+                Some(Mesh::from(Sphere { radius: arrow.cone.radius }).translated_by(tip_center))
+            },
+            BevyTridentTip::None => None,
+        };
+        let mut buffers = if arrow.tip == BevyTridentTip::Cone {
+            self.gen_cone_arrow(dir, settings)
+        } else {
+            let shaft = gen_cylinder(
+                axis, 0.0, arrow.tail_length, arrow.tail_radius, arrow.tail_subdivisions, self.color(dir), settings
+            );
+            merge_buffers(std::iter::once(shaft).chain(tip.map(|tip| mesh_buffers(&tip, self.color(dir)))))
+        };
+        if let Some(ticks) = self.gen_ticks(dir, settings) {
+            buffers = merge_buffers([buffers, ticks]);
         }
-        let mut colors: Vec<[f32; 4]> = vec![];
-        colors.resize_with(
-            positions.len(),
-            || {
-                match dir {
-                    0 => [1.0, 0.0, 0.0, 1.0],
-                    1 => [0.0, 1.0, 0.0, 1.0],
-                    2 => [0.0, 0.0, 1.0, 1.0],
-                    _ => panic!("Invalid axis"),
-                }
+        // Arrows are unwrapped on whole texture, atlas puts each in its own quarter.
+        if settings.uv_layout == BevyTridentUvLayout::Atlas {
+            let uv_stride = match dir {
+                0 => Vec2::ZERO,
+                1 => Vec2::new(0.5, 0.0),
+                2 => Vec2::new(0.0, 0.5),
+                _ => panic!("Invalid axis"),
+            };
+            for uv in buffers.2.iter_mut() {
+                *uv = (Vec2::from(*uv) / 2.0 + uv_stride).into();
             }
-        );
+        }
+        buffers
+    }
+
+    /// Shaft with cone, first uv coordinate runs from tip to base.
+    fn gen_cone_arrow(&self, dir: usize, settings: &BevyTridentMeshSettings) -> MeshBuffers {
+        let arrow = &self.axises[dir];
+        let (d, shaft) = (arrow.cone.subdivisions, arrow.tail_subdivisions);
+        let mut axis = Vec3::ZERO;
+        axis[dir] = 1.0;
+
+        let mut parts = vec![
+            gen_cone(axis, arrow.tail_length, arrow.cone.radius, arrow.cone.height, d, settings.cone_shading),
+            gen_tube(axis, 0.0, arrow.tail_length, arrow.tail_radius, shaft, settings.shaft_segments, (0.75, 0.5)),
+        ];
+        if settings.cap == BevyTridentCap::Closed {
+            // underside of cone around shaft
+            parts.push(gen_annulus(
+                axis, arrow.tail_length, (arrow.tail_radius, shaft), (arrow.cone.radius, d), -axis, (0.5, 0.25)
+            ));
+            // base of shaft
+            parts.push(gen_annulus(axis, 0.0, (0.0, shaft), (arrow.tail_radius, shaft), -axis, (1.0, 0.75)));
+        }
+        paint_buffers(merge_buffers(parts), self.color(dir))
+    }
 
-        (
-            positions,
-            normals,
-            uvs,
-            indices,
-            colors
-        )
+    /// Negative half axis, mirrored by rotating 180 degrees.
+    fn gen_negative(&self, dir: usize, settings: &BevyTridentMeshSettings) -> Vec<MeshBuffers> {
+        let arrow = &self.axises[dir];
+        let mut axis = Vec3::ZERO;
+        axis[dir] = -1.0;
+        match self.negative {
+            BevyTridentNegative::None => vec![],
+            BevyTridentNegative::Shaft { radius_scale, dashes } => {
+                let length = arrow.length();
+                let radius = arrow.tail_radius * radius_scale;
+                let subdivisions = arrow.tail_subdivisions;
+                if dashes == 0 {
+                    return vec![gen_cylinder(axis, 0.0, length, radius, subdivisions, self.color(dir), settings)];
+                }
+                // Dashes and gaps of same length.
+                let step = length / (2 * dashes - 1) as f32;
+                (0..dashes).map(|i| {
+                    let start = 2.0 * i as f32 * step;
+                    gen_cylinder(axis, start, start + step, radius, subdivisions, self.color(dir), settings)
+                }).collect()
+            },
+            BevyTridentNegative::Dimmed { dim } => {
+                let mut buffers = self.gen_axis(dir, settings);
+                let mut flip = Vec3::ZERO;
+                flip[(dir + 1) % 3] = 1.0;
+                rotate_buffers(&mut buffers, Quat::from_axis_angle(flip, std::f32::consts::PI));
+                let [r, g, b, a] = self.color(dir).as_linear_rgba_f32();
+                buffers.4.fill([r * dim, g * dim, b * dim, a]);
+                vec![buffers]
+            },
+        }
     }
 
-    fn gen_origin(&self, shift: usize)
-    -> (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<[f32; 2]>, Vec<u32>, Vec<[f32; 4]>)  {
+    fn gen_origin(&self) -> MeshBuffers {
         let mut radius = self.axises[0].tail_radius;
         self.axises.iter().skip(1).for_each(|axis| {
             if axis.tail_radius > radius {
@@ -223,48 +508,215 @@ impl BevyTridentAxis {
         });
         // Choose largest radius for sphere
         let sphere = Sphere{radius};
-        let mesh = Mesh::from(sphere);
+        mesh_buffers(&Mesh::from(sphere), self.origin_color)
+    }
+}
 
-        let positions = if let Some(VertexAttributeValues::Float32x3(vert_positions)) =
-            &mesh.attribute(Mesh::ATTRIBUTE_POSITION)
-        {
-            vert_positions.clone()
-        } else {
-            panic!("no positions")
-        };
-        let normals = if let Some(VertexAttributeValues::Float32x3(normals)) =
-            &mesh.attribute(Mesh::ATTRIBUTE_NORMAL)
-        {
-            normals.clone()
-        } else {
-            panic!("no normals")
+/// Buffers of a primitive mesh painted with one color.
+fn mesh_buffers(mesh: &Mesh, color: Color) -> MeshBuffers {
+    let positions = if let Some(VertexAttributeValues::Float32x3(vert_positions)) =
+        &mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    {
+        vert_positions.clone()
+    } else {
+        panic!("no positions")
+    };
+    let normals = if let Some(VertexAttributeValues::Float32x3(normals)) =
+        &mesh.attribute(Mesh::ATTRIBUTE_NORMAL)
+    {
+        normals.clone()
+    } else {
+        panic!("no normals")
+    };
+    let uvs = if let Some(VertexAttributeValues::Float32x2(uvs)) =
+        &mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+    {
+        uvs.clone()
+    } else {
+        panic!("no uvs")
+    };
+
+    let indices: Vec<u32> = match &mesh.indices() {
+        Some(Indices::U32(indices)) => indices.clone(),
+        Some(Indices::U16(indices)) => indices.iter().map(|i| *i as u32).collect(),
+        None => panic!("no indices"),
+    };
+    let mut colors: Vec<[f32; 4]> = vec![];
+    colors.resize_with(positions.len(), || color.as_linear_rgba_f32());
+
+    (
+        positions,
+        normals,
+        uvs,
+        indices,
+        colors
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::Assets;
+
+    use super::*;
+    use crate::mesh_validation::assert_valid;
+
+    /// Vertices and indices of a closed cone arrow counted from its parts:
+    /// cone, tube, annulus under cone and disc at base.
+    fn cone_arrow_counts(cone: usize, shaft: usize) -> (usize, usize) {
+        let vertices = (2 * cone + 1) + 2 * (shaft + 1) + (shaft + 1 + cone + 1) + (shaft + 2);
+        let triangles = cone + 2 * shaft + (shaft + cone) + shaft;
+        (vertices, 3 * triangles)
+    }
+
+    /// Counts of a default trident whose arrows have given subdivisions.
+    fn trident_counts(cone: usize, shaft: usize, radius: f32) -> (usize, usize) {
+        let sphere = Mesh::from(Sphere { radius });
+        let (vertices, indices) = cone_arrow_counts(cone, shaft);
+        (3 * vertices + sphere.count_vertices(), 3 * indices + sphere.indices().unwrap().len())
+    }
+
+    fn counts(mesh: &Mesh) -> (usize, usize) {
+        (mesh.count_vertices(), mesh.indices().unwrap().len())
+    }
+
+    #[test]
+    fn predefined_tridents_are_valid() {
+        for trident in [BevyTridentAxis::default(), BevyTridentAxis::TRIDENT_10, BevyTridentAxis::TRIDENT_100] {
+            assert_valid(&Mesh::from(trident));
+        }
+    }
+
+    #[test]
+    fn literal_axis_keeps_axis_colors() {
+        let trident = BevyTridentAxis {
+            axises: [BevyTridentArrow::new(1.0, 0.04, 8); 3],
+            ..Default::default()
         };
-        let uvs = if let Some(VertexAttributeValues::Float32x2(uvs)) =
-            &mesh.attribute(Mesh::ATTRIBUTE_UV_0)
-        {
-            uvs.clone()
-        } else {
-            panic!("no uvs")
+        let mesh = Mesh::from(trident);
+        let Some(VertexAttributeValues::Float32x4(colors)) = mesh.attribute(Mesh::ATTRIBUTE_COLOR) else {
+            panic!("no colors");
         };
+        for color in BevyTridentAxis::COLORS {
+            assert!(colors.contains(&color.as_linear_rgba_f32()), "no vertex of {color:?}");
+        }
+    }
 
-        let shift = shift as u32;
-        let indices: Vec<u32> = if let Some(Indices::U32(indices)) =
-            &mesh.indices()
-        {
-            indices.iter().map(|i| *i + shift).collect()
-        } else {
-            panic!("no indices")
-        };
-        let mut colors: Vec<[f32; 4]> = vec![];
-        colors.resize_with(positions.len(), || [1.0, 1.0, 1.0, 1.0]);
+    #[test]
+    fn builder_settings_are_valid() {
+        let trident = BevyTridentAxis::default();
+        assert_valid(&trident.mesh().subdivisions(3).build());
+        assert_valid(&trident.mesh().shaft_segments(5).cone_shading(BevyTridentShading::Hard).build());
+        assert_valid(&trident.mesh().cap(BevyTridentCap::Open).build());
+        assert_valid(&trident.mesh().uv_layout(BevyTridentUvLayout::Arrow).build());
+        for (cone, shaft) in [(12, 5), (4, 9)] {
+            let mesh = trident.mesh().subdivisions(cone).shaft_subdivisions(shaft).build();
+            assert_valid(&mesh);
+            assert_eq!(counts(&mesh), trident_counts(cone, shaft, 0.04));
+        }
+    }
 
-        (
-            positions,
-            normals,
-            uvs,
-            indices,
-            colors
-        )
+    #[test]
+    fn ticks_are_valid_and_labeled() {
+        let ticks = BevyTridentTicks::new(1.0, 5);
+        assert_eq!(BevyTridentArrow::TRIDENT_ARROW_10.tail_length, 8.0);
+        assert_eq!(ticks.positions(8.0).len(), 8);
+        assert_eq!(ticks.positions(8.0).iter().filter(|(_, major)| *major).count(), 1);
+        let trident = BevyTridentAxis::TRIDENT_10.with_ticks(ticks);
+        assert_valid(&Mesh::from(trident));
+        // Each tick is a closed cylinder around the shaft, 7 per arrow as the one at 8 is under the cone.
+        let (vertices, indices) = trident_counts(8, 8, 0.2);
+        assert_eq!(counts(&Mesh::from(trident)), (vertices + 3 * 7 * (4 * 8 + 6), indices + 3 * 7 * 3 * (4 * 8)));
+        let labels = BevyTridentTickLabels::new(&trident.with_ticks(BevyTridentTicks::new(0.1, 0)));
+        assert_eq!(labels.labels[2].0, "0.3");
+        // Numbers only where a tick is drawn, not at 8 under the cone.
+        assert_eq!(BevyTridentTickLabels::new(&trident.with_ticks(BevyTridentTicks::new(1.0, 0))).labels.len(), 3 * 7);
+
+        // Too dense ticks are spread over the shaft instead of piling up.
+        let dense = BevyTridentTicks::new(1e-6, 10);
+        let positions = dense.positions(80.0);
+        assert_eq!(positions.len(), BevyTridentTicks::MAX_TICKS);
+        assert!((positions.last().unwrap().0 - 80.0).abs() < 1e-3);
+        let (vertices, _) = counts(&Mesh::from(BevyTridentAxis::TRIDENT_100.with_ticks(dense)));
+        assert_eq!(vertices, trident_counts(8, 8, 2.0).0 + 3 * BevyTridentTicks::MAX_TICKS * (4 * 8 + 6));
+    }
+
+    #[test]
+    fn lines_reach_tips() {
+        let trident = BevyTridentAxis::from_directions([Vec3::X, Vec3::new(0.0, 1.0, 1.0), Vec3::NEG_Y]);
+        let lines = trident.lines();
+        for tip in trident.tips() {
+            assert!(lines.iter().any(|(start, end, _)| *start == Vec3::ZERO && end.distance(tip) < 1e-6));
+        }
+        let dashed = trident.with_negative(BevyTridentNegative::Shaft { radius_scale: 0.5, dashes: 3 }).lines();
+        assert_eq!(dashed.len(), lines.len() + 9);
+        let tip_lines = |tip| BevyTridentAxis::from_arrow(BevyTridentArrow::default().with_tip(tip)).lines().len();
+        assert_eq!(tip_lines(BevyTridentTip::Cone), 3 * (1 + 8));
+        assert_eq!(tip_lines(BevyTridentTip::Cube), 3 * (1 + 12));
+        assert_eq!(tip_lines(BevyTridentTip::Sphere), 3 * (1 + 3 * 16));
+        assert_eq!(tip_lines(BevyTridentTip::None), 3);
+
+        // Negative shafts of mesh and lines end at the same point, also without tip.
+        for tip in [BevyTridentTip::Cone, BevyTridentTip::None] {
+            let trident = BevyTridentAxis::from_arrow(BevyTridentArrow::default().with_tip(tip))
+                .with_negative(BevyTridentNegative::Shaft { radius_scale: 0.5, dashes: 0 });
+            let Some(VertexAttributeValues::Float32x3(positions)) = Mesh::from(trident).attribute(Mesh::ATTRIBUTE_POSITION).cloned() else {
+                panic!("missing positions");
+            };
+            let mesh_end = positions.iter().map(|position| position[0]).fold(f32::MAX, f32::min);
+            let line_end = trident.lines().iter().map(|(start, end, _)| start.x.min(end.x)).fold(f32::MAX, f32::min);
+            assert!((mesh_end - line_end).abs() < 1e-6, "{tip:?} mesh ends at {mesh_end}, lines at {line_end}");
+        }
     }
-}
 
+    #[test]
+    fn lod_picks_level_by_screen_size() {
+        let mut meshes = Assets::<Mesh>::default();
+        let lod = BevyTridentLod::new(BevyTridentAxis::default(), &BevyTridentLod::LEVELS, &mut meshes);
+        let level = |pixels: f32| counts(meshes.get(lod.level(pixels).unwrap()).unwrap());
+        assert_eq!(level(-1.0), trident_counts(3, 3, 0.04));
+        assert_eq!(level(10.0), trident_counts(3, 3, 0.04));
+        assert_eq!(level(100.0), trident_counts(8, 4, 0.04));
+        assert_eq!(level(1000.0), trident_counts(16, 8, 0.04));
+    }
+
+    #[test]
+    fn tips_negatives_and_frames_are_valid() {
+        for tip in [BevyTridentTip::Cone, BevyTridentTip::Cube, BevyTridentTip::Sphere, BevyTridentTip::None] {
+            assert_valid(&Mesh::from(BevyTridentAxis::from_arrow(BevyTridentArrow::default().with_tip(tip))));
+        }
+        for negative in [
+            BevyTridentNegative::Shaft { radius_scale: 0.5, dashes: 0 },
+            BevyTridentNegative::Shaft { radius_scale: 0.5, dashes: 4 },
+            BevyTridentNegative::Dimmed { dim: 0.4 },
+        ] {
+            assert_valid(&Mesh::from(BevyTridentAxis::default().with_negative(negative)));
+        }
+        let skewed = BevyTridentAxis::from_directions([Vec3::X, Vec3::new(0.0, 1.0, 1.0), Vec3::NEG_Y]);
+        assert_valid(&Mesh::from(skewed));
+    }
+
+    #[test]
+    fn standalone_arrow_and_cone_are_valid() {
+        let arrow = BevyTridentArrow::default().mesh().start(Vec3::ONE).direction(Vec3::new(1.0, -2.0, 0.5));
+        assert_valid(&arrow.build());
+        assert_valid(&Mesh::from(BevyTridentCone::default()));
+        assert_valid(&BevyTridentCone::default().mesh().cone_shading(BevyTridentShading::Hard).build());
+    }
+
+    #[test]
+    fn standalone_arrows_span_whole_texture() {
+        let uv_range = |mesh: &Mesh| {
+            let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0) else {
+                panic!("missing uvs");
+            };
+            uvs.iter().fold((Vec2::MAX, Vec2::MIN), |(min, max), uv| (min.min(Vec2::from(*uv)), max.max(Vec2::from(*uv))))
+        };
+        let atlas = BevyTridentArrow::default().mesh().uv_layout(BevyTridentUvLayout::Atlas).build();
+        let field = BevyTridentArrowField::default()
+            .with_arrows([(Vec3::ZERO, Vec3::X, Color::RED), (Vec3::Y, Vec3::Z * 3.0, Color::BLUE)]);
+        for mesh in [Mesh::from(BevyTridentArrow::default()), atlas, Mesh::from(field)] {
+            let (min, max) = uv_range(&mesh);
+            assert!(min.abs_diff_eq(Vec2::ZERO, 1e-5) && max.abs_diff_eq(Vec2::ONE, 1e-5), "uvs from {min} to {max}");
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use bevy::render::color::Color;
+
 #[derive(Debug, Copy, Clone)]
 pub struct BevyTridentCone {
     pub radius: f32,
@@ -15,11 +17,29 @@ impl Default for BevyTridentCone {
     }
 }
 
+/// Shape at the end of an arrow, sized by [`BevyTridentCone`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BevyTridentTip {
+    #[default]
+    Cone,
+    /// Box as wide as cone base and as long as cone.
+    Cube,
+    /// Ball of cone radius in the middle of cone.
+    Sphere,
+    /// Shaft only.
+    None,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BevyTridentArrow {
     pub cone: BevyTridentCone,
+    pub tip: BevyTridentTip,
     pub tail_radius: f32,
     pub tail_length: f32,
+    /// Vertices around shaft, cone has its own [`BevyTridentCone::subdivisions`].
+    pub tail_subdivisions: usize,
+    /// Vertex color of arrow, `None` uses color of its axis, see [`crate::prelude::BevyTridentAxis::COLORS`].
+    pub color: Option<Color>,
 }
 
 impl Default for BevyTridentArrow {
@@ -35,8 +55,11 @@ impl BevyTridentArrow {
             height: 2.0,
             subdivisions: 8,
         },
+        tip: BevyTridentTip::Cone,
         tail_length: 8.0,
         tail_radius: 0.2,
+        tail_subdivisions: 8,
+        color: None,
     };
 
     pub const TRIDENT_ARROW_100: BevyTridentArrow = BevyTridentArrow {
@@ -45,8 +68,11 @@ impl BevyTridentArrow {
             height: 20.0,
             subdivisions: 8,
         },
+        tip: BevyTridentTip::Cone,
         tail_length: 80.0,
         tail_radius: 1.0,
+        tail_subdivisions: 8,
+        color: None,
     };
 
     pub fn new(length: f32, radius: f32, subdivisions: usize) -> Self {
@@ -63,8 +89,36 @@ impl BevyTridentArrow {
 
         Self {
             cone: BevyTridentCone { radius: cone_radius, height: cone_length, subdivisions },
+            tip: BevyTridentTip::Cone,
             tail_radius: radius,
             tail_length: length - cone_length,
+            tail_subdivisions: subdivisions,
+            color: None,
+        }
+    }
+
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Vertices around cone and shaft, at least 3.
+    pub const fn with_subdivisions(mut self, cone: usize, shaft: usize) -> Self {
+        self.cone.subdivisions = if cone < 3 { 3 } else { cone };
+        self.tail_subdivisions = if shaft < 3 { 3 } else { shaft };
+        self
+    }
+
+    pub const fn with_tip(mut self, tip: BevyTridentTip) -> Self {
+        self.tip = tip;
+        self
+    }
+
+    /// Distance from origin to end of tip.
+    pub fn length(&self) -> f32 {
+        match self.tip {
+            BevyTridentTip::None => self.tail_length,
+            _ => self.tail_length + self.cone.height,
         }
     }
 }
\ No newline at end of file
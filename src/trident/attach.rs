@@ -0,0 +1,128 @@
+use bevy::{
+    prelude::*,
+    pbr::{ExtendedMaterial, MaterialExtension, MaterialExtensionKey, MaterialExtensionPipeline},
+    render::{
+        mesh::MeshVertexBufferLayout,
+        render_resource::{
+            AsBindGroup, CompareFunction, RenderPipelineDescriptor, SpecializedMeshPipelineError,
+        },
+        view::RenderLayers,
+    },
+};
+
+use crate::follow::FollowTarget;
+
+use super::BevyTridentAxis;
+
+/// Add to any entity to show a trident at its origin.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AttachedTrident {
+    pub axis: BevyTridentAxis,
+    /// Align with rotation of entity, otherwise world axes.
+    pub local: bool,
+    /// Draw over scene geometry, depth test disabled.
+    pub on_top: bool,
+}
+
+impl Default for AttachedTrident {
+    fn default() -> Self {
+        Self {
+            axis: BevyTridentAxis::default(),
+            local: true,
+            on_top: false,
+        }
+    }
+}
+
+/// Material extension drawing without depth test.
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone, Default)]
+pub(crate) struct OnTop {}
+
+impl MaterialExtension for OnTop {
+    fn specialize(
+        _pipeline: &MaterialExtensionPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayout,
+        _key: MaterialExtensionKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        if let Some(depth_stencil) = descriptor.depth_stencil.as_mut() {
+            depth_stencil.depth_compare = CompareFunction::Always;
+            depth_stencil.depth_write_enabled = false;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) type OnTopMaterial = ExtendedMaterial<StandardMaterial, OnTop>;
+
+/// Trident entity following this entity.
+#[derive(Component)]
+pub(crate) struct AttachedTridentEntity(Entity);
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn spawn_attached_trident(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut on_top_materials: ResMut<Assets<OnTopMaterial>>,
+    targets: Query<
+        (Entity, &AttachedTrident, Option<&RenderLayers>, Option<&AttachedTridentEntity>),
+        Or<(Changed<AttachedTrident>, Without<AttachedTridentEntity>)>
+    >,
+) {
+    for (target, attached, layers, spawned) in targets.iter() {
+        if let Some(spawned) = spawned {
+            commands.entity(spawned.0).despawn_recursive();
+        }
+        let root = FollowTarget {
+            target,
+            local: attached.local,
+        };
+        let mesh = meshes.add(attached.axis);
+        let mut trident = if attached.on_top {
+            // Transparent pass is drawn after opaque geometry, so nothing covers trident.
+            commands.spawn((
+                MaterialMeshBundle {
+                    mesh,
+                    material: on_top_materials.add(OnTopMaterial {
+                        base: StandardMaterial {
+                            alpha_mode: AlphaMode::Blend,
+                            ..Default::default()
+                        },
+                        extension: OnTop {},
+                    }),
+                    ..Default::default()
+                },
+                root,
+            ))
+        } else {
+            commands.spawn((
+                MaterialMeshBundle {
+                    mesh,
+                    material: materials.add(StandardMaterial::default()),
+                    ..Default::default()
+                },
+                root,
+            ))
+        };
+        if let Some(layers) = layers {
+            trident.insert(*layers);
+        }
+        let trident = trident.id();
+        commands.entity(target).insert(AttachedTridentEntity(trident));
+    }
+}
+
+pub(crate) fn despawn_attached_trident(
+    mut commands: Commands,
+    mut removed: RemovedComponents<AttachedTrident>,
+    targets: Query<&AttachedTridentEntity>,
+) {
+    for target in removed.read() {
+        let Ok(spawned) = targets.get(target) else {
+            continue;
+        };
+        commands.entity(spawned.0).despawn_recursive();
+        commands.entity(target).remove::<AttachedTridentEntity>();
+    }
+}
@@ -0,0 +1,157 @@
+use bevy::{
+    math::Vec3,
+    render::{color::Color, mesh::{Mesh, Meshable}},
+};
+
+use super::{
+    buffers_to_mesh,
+    merge_buffers,
+    BevyTridentArrow,
+    BevyTridentMeshSettings,
+};
+
+/// How long each arrow of a field is drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BevyTridentFieldScale {
+    /// Length is magnitude of vector times factor.
+    Magnitude(f32),
+    /// Same length for all arrows, only direction shown.
+    Fixed(f32),
+}
+
+impl Default for BevyTridentFieldScale {
+    fn default() -> Self {
+        Self::Magnitude(1.0)
+    }
+}
+
+/// Colors along magnitudes from `min` to `max`, evenly spaced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BevyTridentColorMap {
+    pub colors: Vec<Color>,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl BevyTridentColorMap {
+    /// Blue through green to red.
+    pub fn jet(min: f32, max: f32) -> Self {
+        Self {
+            colors: vec![Color::BLUE, Color::CYAN, Color::GREEN, Color::YELLOW, Color::RED],
+            min,
+            max,
+        }
+    }
+
+    pub fn color(&self, magnitude: f32) -> Color {
+        let (Some(first), Some(last)) = (self.colors.first(), self.colors.last()) else {
+            return Color::WHITE;
+        };
+        let range = self.max - self.min;
+        if range <= 0.0 || self.colors.len() == 1 {
+            return if magnitude < self.min { *first } else { *last };
+        }
+        let t = ((magnitude - self.min) / range).clamp(0.0, 1.0) * (self.colors.len() - 1) as f32;
+        let i = (t.floor() as usize).min(self.colors.len() - 2);
+        let (from, to) = (self.colors[i].as_linear_rgba_f32(), self.colors[i + 1].as_linear_rgba_f32());
+        let s = t - i as f32;
+        let [r, g, b, a] = [0, 1, 2, 3].map(|c| from[c] + (to[c] - from[c]) * s);
+        Color::rgba_linear(r, g, b, a)
+    }
+}
+
+/// Many arrows merged into one mesh, e.g. wind or forces on nodes.
+#[derive(Debug, Clone)]
+pub struct BevyTridentArrowField {
+    /// Geometry of an arrow, shaft is stretched to length of each vector.
+    /// Arrows shorter than cone are scaled down as a whole.
+    pub arrow: BevyTridentArrow,
+    /// Origin, vector and color of each arrow.
+    pub arrows: Vec<(Vec3, Vec3, Color)>,
+    pub scale: BevyTridentFieldScale,
+    /// Colors by magnitude, replaces colors of arrows.
+    pub color_map: Option<BevyTridentColorMap>,
+    /// Tessellation of every arrow, each unwrapped on the whole texture.
+    pub settings: BevyTridentMeshSettings,
+}
+
+impl Default for BevyTridentArrowField {
+    fn default() -> Self {
+        Self::new(BevyTridentArrow::default())
+    }
+}
+
+impl BevyTridentArrowField {
+    pub fn new(arrow: BevyTridentArrow) -> Self {
+        Self {
+            arrow,
+            arrows: vec![],
+            scale: BevyTridentFieldScale::default(),
+            color_map: None,
+            settings: BevyTridentMeshSettings::default(),
+        }
+    }
+
+    pub fn with_arrows(mut self, arrows: impl IntoIterator<Item = (Vec3, Vec3, Color)>) -> Self {
+        self.arrows.extend(arrows);
+        self
+    }
+
+    pub fn with_scale(mut self, scale: BevyTridentFieldScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_color_map(mut self, color_map: BevyTridentColorMap) -> Self {
+        self.color_map = Some(color_map);
+        self
+    }
+
+    pub fn with_settings(mut self, settings: BevyTridentMeshSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Arrow drawn for a vector, `None` for zero vectors.
+    fn arrow_for(&self, vector: Vec3, color: Color) -> Option<BevyTridentArrow> {
+        let magnitude = vector.length();
+        let length = match self.scale {
+            BevyTridentFieldScale::Magnitude(factor) => magnitude * factor,
+            BevyTridentFieldScale::Fixed(length) => length,
+        };
+        if magnitude == 0.0 || length <= 0.0 {
+            return None;
+        }
+        let color = self.color_map.as_ref().map_or(color, |map| map.color(magnitude));
+        let mut arrow = self.arrow.with_color(color);
+        let tip = arrow.length() - arrow.tail_length;
+        if length > tip {
+            arrow.tail_length = length - tip;
+        } else {
+            let ratio = length / arrow.length();
+            arrow.tail_length *= ratio;
+            arrow.tail_radius *= ratio;
+            arrow.cone.radius *= ratio;
+            arrow.cone.height *= ratio;
+        }
+        Some(arrow)
+    }
+}
+
+impl From<&BevyTridentArrowField> for Mesh {
+    fn from(field: &BevyTridentArrowField) -> Self {
+        let parts = field.arrows.iter().filter_map(|(origin, vector, color)| {
+            let arrow = field.arrow_for(*vector, *color)?;
+            let mut builder = arrow.mesh().start(*origin).direction(*vector);
+            builder.settings = field.settings;
+            Some(builder.gen())
+        });
+        buffers_to_mesh(merge_buffers(parts))
+    }
+}
+
+impl From<BevyTridentArrowField> for Mesh {
+    fn from(field: BevyTridentArrowField) -> Self {
+        Mesh::from(&field)
+    }
+}
@@ -0,0 +1,208 @@
+use bevy::{
+    prelude::*,
+    render::view::RenderLayers,
+};
+
+use crate::label::BillboardLabel;
+
+use super::BevyTridentAxis;
+
+/// Spawns and despawns label entities of [`BevyTridentLabels`] and [`BevyTridentTickLabels`].
+/// Added by `BevyTridentPlugin` and by viewcube with trident labels, whichever comes first.
+pub(crate) struct TridentLabelPlugin;
+
+impl Plugin for TridentLabelPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<crate::label::BillboardLabelPlugin>() {
+            app.add_plugins(crate::label::BillboardLabelPlugin);
+        }
+        app.add_systems(Update, (
+            spawn_trident_labels,
+            despawn_trident_labels,
+            spawn_tick_labels,
+            despawn_tick_labels,
+        ));
+    }
+}
+
+/// Text labels at arrow tips of a trident entity, always facing screen.
+#[derive(Component, Debug, Clone)]
+pub struct BevyTridentLabels {
+    pub texts: [String; 3],
+    pub colors: [Color; 3],
+    pub font_size: f32,
+    /// Label positions in local space of trident.
+    pub positions: [Vec3; 3],
+    /// Camera rendering trident, `None` picks the camera sharing render layers with trident.
+    pub camera: Option<Entity>,
+}
+
+impl Default for BevyTridentLabels {
+    fn default() -> Self {
+        Self::new(&BevyTridentAxis::default())
+    }
+}
+
+impl BevyTridentLabels {
+    /// X, Y and Z labels placed beyond tips of `axis`, colored as axis.
+    pub fn new(axis: &BevyTridentAxis) -> Self {
+        let mut positions = axis.tips();
+        for (position, arrow) in positions.iter_mut().zip(axis.axises.iter()) {
+            *position += position.normalize_or_zero() * arrow.cone.height;
+        }
+        Self {
+            texts: ["X".into(), "Y".into(), "Z".into()],
+            colors: [axis.color(0), axis.color(1), axis.color(2)],
+            font_size: 16.0,
+            positions,
+            camera: None,
+        }
+    }
+}
+
+/// Numbers at ticks of a trident entity, see [`BevyTridentAxis::ticks`].
+#[derive(Component, Debug, Clone)]
+pub struct BevyTridentTickLabels {
+    /// Text, position in local space of trident and color of each label.
+    pub labels: Vec<(String, Vec3, Color)>,
+    pub font_size: f32,
+    /// Camera rendering trident, `None` picks the camera sharing render layers with trident.
+    pub camera: Option<Entity>,
+}
+
+impl BevyTridentTickLabels {
+    /// Distance at major ticks of `axis`, or at every tick when none is major, beside the shaft.
+    pub fn new(axis: &BevyTridentAxis) -> Self {
+        let mut labels = vec![];
+        for (dir, arrow) in axis.axises.iter().enumerate() {
+            let Some(ticks) = axis.ticks else {
+                break;
+            };
+            let direction = axis.direction(dir);
+            let side = direction.any_orthonormal_vector() * arrow.cone.radius * 2.0;
+            let positions = ticks.on_shaft(arrow);
+            let labeled = positions.iter().filter(|(_, major)| *major || ticks.major_every == 0);
+            labels.extend(labeled.map(|(distance, _)| {
+                // Rounded to hide float error of multiplied spacing.
+                let text = format!("{}", (distance * 1e4).round() / 1e4);
+                (text, direction * *distance + side, axis.color(dir))
+            }));
+        }
+        Self {
+            labels,
+            font_size: 12.0,
+            camera: None,
+        }
+    }
+}
+
+/// Label entities spawned for a trident.
+#[derive(Component)]
+pub(crate) struct TridentLabelEntities([Entity; 3]);
+
+/// Label entities spawned for ticks of a trident.
+#[derive(Component)]
+pub(crate) struct TickLabelEntities(Vec<Entity>);
+
+/// Camera given or the highest order active camera sharing render layers.
+fn label_camera(
+    camera: Option<Entity>, layers: Option<&RenderLayers>, cameras: &Query<(Entity, &Camera, Option<&RenderLayers>)>,
+) -> Option<Entity> {
+    let layers = layers.copied().unwrap_or_default();
+    camera.or_else(|| cameras.iter()
+        .filter(|(_, camera, camera_layers)|
+            camera.is_active && camera_layers.copied().unwrap_or_default().intersects(&layers)
+        )
+        .max_by_key(|(_, camera, _)| camera.order)
+        .map(|(camera, _, _)| camera)
+    )
+}
+
+#[allow(clippy::type_complexity)]
+fn spawn_trident_labels(
+    mut commands: Commands,
+    tridents: Query<
+        (Entity, &BevyTridentLabels, Option<&RenderLayers>, Option<&TridentLabelEntities>),
+        Or<(Changed<BevyTridentLabels>, Without<TridentLabelEntities>)>
+    >,
+    cameras: Query<(Entity, &Camera, Option<&RenderLayers>)>,
+) {
+    for (entity, labels, layers, spawned) in tridents.iter() {
+        if let Some(spawned) = spawned {
+            spawned.0.iter().for_each(|label| commands.entity(*label).despawn_recursive());
+            commands.entity(entity).remove::<TridentLabelEntities>();
+        }
+        let camera = label_camera(labels.camera, layers, &cameras);
+        // Camera may not be spawned yet.
+        let Some(camera) = camera else {
+            continue;
+        };
+        let spawned = [0, 1, 2].map(|i| commands.spawn((
+            BillboardLabel::text_bundle(labels.texts[i].clone(), labels.colors[i], labels.font_size),
+            BillboardLabel {
+                anchor: entity,
+                offset: labels.positions[i],
+            },
+            TargetCamera(camera),
+        )).id());
+        commands.entity(entity).insert(TridentLabelEntities(spawned));
+    }
+}
+
+fn despawn_trident_labels(
+    mut commands: Commands,
+    mut removed: RemovedComponents<BevyTridentLabels>,
+    tridents: Query<&TridentLabelEntities>,
+) {
+    for entity in removed.read() {
+        let Ok(spawned) = tridents.get(entity) else {
+            continue;
+        };
+        spawned.0.iter().for_each(|label| commands.entity(*label).despawn_recursive());
+        commands.entity(entity).remove::<TridentLabelEntities>();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn spawn_tick_labels(
+    mut commands: Commands,
+    tridents: Query<
+        (Entity, &BevyTridentTickLabels, Option<&RenderLayers>, Option<&TickLabelEntities>),
+        Or<(Changed<BevyTridentTickLabels>, Without<TickLabelEntities>)>
+    >,
+    cameras: Query<(Entity, &Camera, Option<&RenderLayers>)>,
+) {
+    for (entity, labels, layers, spawned) in tridents.iter() {
+        if let Some(spawned) = spawned {
+            spawned.0.iter().for_each(|label| commands.entity(*label).despawn_recursive());
+            commands.entity(entity).remove::<TickLabelEntities>();
+        }
+        // Camera may not be spawned yet.
+        let Some(camera) = label_camera(labels.camera, layers, &cameras) else {
+            continue;
+        };
+        let spawned = labels.labels.iter().map(|(text, position, color)| commands.spawn((
+            BillboardLabel::text_bundle(text.clone(), *color, labels.font_size),
+            BillboardLabel {
+                anchor: entity,
+                offset: *position,
+            },
+            TargetCamera(camera),
+        )).id()).collect();
+        commands.entity(entity).insert(TickLabelEntities(spawned));
+    }
+}
+
+fn despawn_tick_labels(
+    mut commands: Commands,
+    mut removed: RemovedComponents<BevyTridentTickLabels>,
+    tridents: Query<&TickLabelEntities>,
+) {
+    for entity in removed.read() {
+        let Ok(spawned) = tridents.get(entity) else {
+            continue;
+        };
+        spawned.0.iter().for_each(|label| commands.entity(*label).despawn_recursive());
+        commands.entity(entity).remove::<TickLabelEntities>();
+    }
+}
@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+use super::{
+    BevyTridentArrow,
+    BevyTridentAxis,
+    BevyTridentNegative,
+    BevyTridentTip,
+};
+
+/// Trident drawn with gizmo lines every frame instead of a mesh, needs `BevyTridentPlugin`.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct BevyTridentLines {
+    /// Directions, lengths and colors of arrows, radii only size arrowheads.
+    pub axis: BevyTridentAxis,
+}
+
+/// Draw default trident at `transform`, longest arrow `size` long.
+pub fn draw_trident<T: GizmoConfigGroup>(gizmos: &mut Gizmos<T>, transform: impl Into<GlobalTransform>, size: f32) {
+    let axis = BevyTridentAxis::default();
+    let longest = axis.axises.iter().map(|arrow| arrow.length()).fold(0.0, f32::max);
+    let scale = if longest > 0.0 { size / longest } else { size };
+    axis.draw(gizmos, transform.into() * Transform::from_scale(Vec3::splat(scale)));
+}
+
+/// Segments around a circle.
+const CIRCLE_SEGMENTS: usize = 16;
+
+/// Shaft and tip along `direction`, outlined as on mesh tridents.
+/// Cone is four lines from tip to rim of its base, cube its edges and sphere three circles.
+fn arrow_lines(direction: Vec3, arrow: &BevyTridentArrow, color: Color, lines: &mut Vec<(Vec3, Vec3, Color)>) {
+    let base = direction * arrow.tail_length;
+    let u = direction.any_orthonormal_vector() * arrow.cone.radius;
+    let v = direction.cross(u);
+    match arrow.tip {
+        BevyTridentTip::Cone => {
+            let tip = direction * arrow.length();
+            lines.push((Vec3::ZERO, tip, color));
+            let rim = [u, v, -u, -v].map(|radial| base + radial);
+            for (i, point) in rim.iter().enumerate() {
+                lines.push((tip, *point, color));
+                lines.push((*point, rim[(i + 1) % 4], color));
+            }
+        },
+        BevyTridentTip::Cube => {
+            lines.push((Vec3::ZERO, base, color));
+            let top = direction * arrow.cone.height;
+            let corners = [u + v, u - v, -u - v, -u + v].map(|radial| base + radial);
+            for (i, corner) in corners.iter().enumerate() {
+                let next = corners[(i + 1) % 4];
+                lines.extend([(*corner, next, color), (*corner + top, next + top, color), (*corner, *corner + top, color)]);
+            }
+        },
+        BevyTridentTip::Sphere => {
+            lines.push((Vec3::ZERO, base, color));
+            let center = base + direction * arrow.cone.height / 2.0;
+            let axial = direction * arrow.cone.radius;
+            for (a, b) in [(u, v), (u, axial), (v, axial)] {
+                let point = |i: usize| {
+                    let phi = i as f32 / CIRCLE_SEGMENTS as f32 * std::f32::consts::TAU;
+                    center + a * phi.cos() + b * phi.sin()
+                };
+                lines.extend((0..CIRCLE_SEGMENTS).map(|i| (point(i), point(i + 1), color)));
+            }
+        },
+        BevyTridentTip::None => lines.push((Vec3::ZERO, base, color)),
+    }
+}
+
+impl BevyTridentAxis {
+    /// Segments and colors of trident drawn with lines, in local space.
+    pub fn lines(&self) -> Vec<(Vec3, Vec3, Color)> {
+        let mut lines = vec![];
+        for (dir, arrow) in self.axises.iter().enumerate() {
+            let direction = self.direction(dir);
+            let color = self.color(dir);
+            arrow_lines(direction, arrow, color, &mut lines);
+            match self.negative {
+                BevyTridentNegative::None => {},
+                BevyTridentNegative::Shaft { dashes, .. } => {
+                    let end = -direction * arrow.length();
+                    if dashes == 0 {
+                        lines.push((Vec3::ZERO, end, color));
+                    } else {
+                        // Dashes and gaps of same length.
+                        let step = end / (2 * dashes - 1) as f32;
+                        lines.extend((0..dashes).map(|i| {
+                            (2.0 * i as f32 * step, (2 * i + 1) as f32 * step, color)
+                        }));
+                    }
+                },
+                BevyTridentNegative::Dimmed { dim } => {
+                    let [r, g, b, a] = color.as_linear_rgba_f32();
+                    arrow_lines(-direction, arrow, Color::rgba_linear(r * dim, g * dim, b * dim, a), &mut lines);
+                },
+            }
+        }
+        lines
+    }
+
+    /// Draw trident with gizmo lines at `transform`.
+    pub fn draw<T: GizmoConfigGroup>(&self, gizmos: &mut Gizmos<T>, transform: impl Into<GlobalTransform>) {
+        let transform = transform.into();
+        for (start, end, color) in self.lines() {
+            gizmos.line(transform.transform_point(start), transform.transform_point(end), color);
+        }
+    }
+}
+
+pub(crate) fn draw_trident_lines(
+    mut gizmos: Gizmos,
+    tridents: Query<(&BevyTridentLines, &GlobalTransform, Option<&InheritedVisibility>)>,
+) {
+    for (lines, transform, visibility) in tridents.iter() {
+        if visibility.is_some_and(|visibility| !visibility.get()) {
+            continue;
+        }
+        lines.axis.draw(&mut gizmos, *transform);
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::{
+    prelude::*,
+    render::view::RenderLayers,
+    transform::helper::TransformHelper,
+};
+
+use super::{
+    screen_size::{measure, ScreenCameras},
+    BevyTridentAxis,
+};
+
+/// Swap mesh of entity for coarser ones as it gets smaller on screen.
+/// Meshes are shared handles, so many tridents can use the same levels.
+#[derive(Component, Debug, Clone)]
+pub struct BevyTridentLod {
+    /// Smallest on-screen length in pixels each mesh is used from.
+    /// Below all of them the smallest level is used.
+    pub levels: Vec<(f32, Handle<Mesh>)>,
+    /// Length in local units measured on screen, e.g. longest arrow.
+    pub length: f32,
+    /// Camera viewing entity, `None` picks the camera sharing render layers with entity.
+    pub camera: Option<Entity>,
+}
+
+impl BevyTridentLod {
+    /// Pixels from which cone and shaft subdivisions are used, a trident a few pixels long needs only triangles.
+    pub const LEVELS: [(f32, usize, usize); 3] = [(0.0, 3, 3), (40.0, 8, 4), (150.0, 16, 8)];
+
+    /// Meshes of `trident` for pixels, cone and shaft subdivisions of each level.
+    pub fn new(trident: BevyTridentAxis, levels: &[(f32, usize, usize)], meshes: &mut Assets<Mesh>) -> Self {
+        let levels = levels.iter().map(|&(pixels, cone, shaft)| {
+            (pixels, meshes.add(trident.mesh().subdivisions(cone).shaft_subdivisions(shaft)))
+        }).collect();
+        Self {
+            levels,
+            length: trident.axises.iter().map(|arrow| arrow.length()).fold(0.0, f32::max),
+            camera: None,
+        }
+    }
+
+    /// Mesh used at length of `pixels` on screen.
+    pub fn level(&self, pixels: f32) -> Option<&Handle<Mesh>> {
+        let by_pixels = |a: &&(f32, Handle<Mesh>), b: &&(f32, Handle<Mesh>)| a.0.total_cmp(&b.0);
+        self.levels.iter()
+            .filter(|(from, _)| *from <= pixels)
+            .max_by(by_pixels)
+            .or_else(|| self.levels.iter().min_by(by_pixels))
+            .map(|(_, mesh)| mesh)
+    }
+}
+
+pub(crate) fn select_lod(
+    mut entities: Query<(Entity, &BevyTridentLod, &mut Handle<Mesh>, Option<&RenderLayers>)>,
+    cameras: ScreenCameras,
+    helper: TransformHelper,
+) {
+    for (entity, lod, mut mesh, layers) in entities.iter_mut() {
+        let Some((global, pixel)) = measure(entity, layers, lod.camera, &cameras, &helper) else {
+            continue;
+        };
+        let scale = global.compute_transform().scale.abs().max_element();
+        let Some(level) = lod.level(lod.length * scale / pixel) else {
+            continue;
+        };
+        if *mesh != *level {
+            *mesh = level.clone();
+        }
+    }
+}
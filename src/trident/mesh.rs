@@ -0,0 +1,284 @@
+use bevy::{
+    math::{primitives::Primitive3d, Quat, Vec3},
+    render::{color::Color, mesh::{Mesh, Meshable}},
+};
+
+use super::{
+    buffers_to_mesh,
+    gen_annulus,
+    gen_cone,
+    merge_buffers,
+    paint_buffers,
+    rotate_buffers,
+    BevyTridentArrow,
+    BevyTridentAxis,
+    BevyTridentCone,
+    MeshBuffers,
+};
+
+/// Ends of shafts and base of cones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BevyTridentCap {
+    #[default]
+    Closed,
+    Open,
+}
+
+/// How texture coordinates are laid out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BevyTridentUvLayout {
+    /// X, Y and Z arrows each in a quarter of texture, single arrows and fields use `Arrow` instead.
+    #[default]
+    Atlas,
+    /// Every arrow spans whole texture.
+    Arrow,
+}
+
+/// Normals of cone sides.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BevyTridentShading {
+    #[default]
+    Smooth,
+    /// Flat faces.
+    Hard,
+}
+
+/// Tessellation and layout shared by trident mesh builders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BevyTridentMeshSettings {
+    /// Vertices around shafts and cones, `None` keeps [`BevyTridentCone::subdivisions`]
+    /// and [`BevyTridentArrow::tail_subdivisions`].
+    pub subdivisions: Option<usize>,
+    /// Vertices around shafts only, overrides `subdivisions` for them.
+    pub shaft_subdivisions: Option<usize>,
+    /// Rings along shafts.
+    pub shaft_segments: usize,
+    pub cap: BevyTridentCap,
+    pub cone_shading: BevyTridentShading,
+    pub uv_layout: BevyTridentUvLayout,
+}
+
+impl Default for BevyTridentMeshSettings {
+    fn default() -> Self {
+        Self {
+            subdivisions: None,
+            shaft_subdivisions: None,
+            shaft_segments: 1,
+            cap: BevyTridentCap::Closed,
+            cone_shading: BevyTridentShading::Smooth,
+            uv_layout: BevyTridentUvLayout::Atlas,
+        }
+    }
+}
+
+/// Chained setters of [`BevyTridentMeshSettings`].
+macro_rules! mesh_settings {
+    () => {
+        pub const fn subdivisions(mut self, subdivisions: usize) -> Self {
+            self.settings.subdivisions = Some(subdivisions);
+            self
+        }
+
+        pub const fn shaft_subdivisions(mut self, subdivisions: usize) -> Self {
+            self.settings.shaft_subdivisions = Some(subdivisions);
+            self
+        }
+
+        pub const fn shaft_segments(mut self, segments: usize) -> Self {
+            self.settings.shaft_segments = segments;
+            self
+        }
+
+        pub const fn cap(mut self, cap: BevyTridentCap) -> Self {
+            self.settings.cap = cap;
+            self
+        }
+
+        pub const fn cone_shading(mut self, shading: BevyTridentShading) -> Self {
+            self.settings.cone_shading = shading;
+            self
+        }
+
+        pub const fn uv_layout(mut self, uv_layout: BevyTridentUvLayout) -> Self {
+            self.settings.uv_layout = uv_layout;
+            self
+        }
+    };
+}
+
+fn with_subdivisions(mut arrow: BevyTridentArrow, settings: &BevyTridentMeshSettings) -> BevyTridentArrow {
+    if let Some(subdivisions) = settings.subdivisions {
+        arrow = arrow.with_subdivisions(subdivisions, subdivisions);
+    }
+    if let Some(subdivisions) = settings.shaft_subdivisions {
+        arrow = arrow.with_subdivisions(arrow.cone.subdivisions, subdivisions);
+    }
+    arrow
+}
+
+/// Builder of trident meshes, see [`BevyTridentAxis::mesh`].
+#[derive(Debug, Clone, Copy)]
+pub struct BevyTridentAxisMeshBuilder {
+    pub axis: BevyTridentAxis,
+    pub settings: BevyTridentMeshSettings,
+}
+
+impl BevyTridentAxisMeshBuilder {
+    mesh_settings!();
+
+    pub fn build(&self) -> Mesh {
+        let mut trident = self.axis;
+        trident.axises = trident.axises.map(|arrow| with_subdivisions(arrow, &self.settings));
+        let mut parts = vec![];
+        for dir in 0..3 {
+            let rotation = trident.rotation(dir);
+            let mut arrow = vec![trident.gen_axis(dir, &self.settings)];
+            arrow.extend(trident.gen_negative(dir, &self.settings));
+            arrow.iter_mut().for_each(|buffers| rotate_buffers(buffers, rotation));
+            parts.extend(arrow);
+        }
+        parts.push(trident.gen_origin());
+        buffers_to_mesh(merge_buffers(parts))
+    }
+}
+
+impl Primitive3d for BevyTridentAxis {}
+
+impl Meshable for BevyTridentAxis {
+    type Output = BevyTridentAxisMeshBuilder;
+
+    fn mesh(&self) -> Self::Output {
+        BevyTridentAxisMeshBuilder {
+            axis: *self,
+            settings: BevyTridentMeshSettings::default(),
+        }
+    }
+}
+
+impl From<BevyTridentAxisMeshBuilder> for Mesh {
+    fn from(builder: BevyTridentAxisMeshBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builder of single arrow meshes, see [`BevyTridentArrow::mesh`].
+#[derive(Debug, Clone, Copy)]
+pub struct BevyTridentArrowMeshBuilder {
+    pub arrow: BevyTridentArrow,
+    pub settings: BevyTridentMeshSettings,
+    /// Arrow points along direction, +Y by default.
+    pub direction: Vec3,
+    /// Base of arrow, origin by default.
+    pub start: Vec3,
+}
+
+impl BevyTridentArrowMeshBuilder {
+    mesh_settings!();
+
+    pub const fn direction(mut self, direction: Vec3) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub const fn start(mut self, start: Vec3) -> Self {
+        self.start = start;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        buffers_to_mesh(self.gen())
+    }
+
+    pub(crate) fn gen(&self) -> MeshBuffers {
+        let mut trident = BevyTridentAxis::from_arrow(with_subdivisions(self.arrow, &self.settings));
+        trident.axises[1].color = Some(self.arrow.color.unwrap_or(Color::WHITE));
+        // No other arrows to share texture with.
+        let settings = BevyTridentMeshSettings {
+            uv_layout: BevyTridentUvLayout::Arrow,
+            ..self.settings
+        };
+        let mut buffers = trident.gen_axis(1, &settings);
+        let direction = self.direction.normalize_or_zero();
+        if direction != Vec3::ZERO {
+            rotate_buffers(&mut buffers, Quat::from_rotation_arc(Vec3::Y, direction));
+        }
+        for position in buffers.0.iter_mut() {
+            *position = (Vec3::from(*position) + self.start).into();
+        }
+        buffers
+    }
+}
+
+impl Primitive3d for BevyTridentArrow {}
+
+impl Meshable for BevyTridentArrow {
+    type Output = BevyTridentArrowMeshBuilder;
+
+    fn mesh(&self) -> Self::Output {
+        BevyTridentArrowMeshBuilder {
+            arrow: *self,
+            settings: BevyTridentMeshSettings::default(),
+            direction: Vec3::Y,
+            start: Vec3::ZERO,
+        }
+    }
+}
+
+impl From<BevyTridentArrowMeshBuilder> for Mesh {
+    fn from(builder: BevyTridentArrowMeshBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Arrow along +Y from origin.
+impl From<BevyTridentArrow> for Mesh {
+    fn from(arrow: BevyTridentArrow) -> Self {
+        arrow.mesh().build()
+    }
+}
+
+/// Builder of cone meshes along +Y with base at origin, see [`BevyTridentCone::mesh`].
+#[derive(Debug, Clone, Copy)]
+pub struct BevyTridentConeMeshBuilder {
+    pub cone: BevyTridentCone,
+    pub settings: BevyTridentMeshSettings,
+}
+
+impl BevyTridentConeMeshBuilder {
+    mesh_settings!();
+
+    pub fn build(&self) -> Mesh {
+        let d = self.settings.subdivisions.unwrap_or(self.cone.subdivisions).max(3);
+        let (radius, height) = (self.cone.radius, self.cone.height);
+        let mut parts = vec![gen_cone(Vec3::Y, 0.0, radius, height, d, self.settings.cone_shading)];
+        if self.settings.cap == BevyTridentCap::Closed {
+            parts.push(gen_annulus(Vec3::Y, 0.0, (0.0, d), (radius, d), Vec3::NEG_Y, (1.0, 0.25)));
+        }
+        buffers_to_mesh(paint_buffers(merge_buffers(parts), Color::WHITE))
+    }
+}
+
+impl Primitive3d for BevyTridentCone {}
+
+impl Meshable for BevyTridentCone {
+    type Output = BevyTridentConeMeshBuilder;
+
+    fn mesh(&self) -> Self::Output {
+        BevyTridentConeMeshBuilder {
+            cone: *self,
+            settings: BevyTridentMeshSettings::default(),
+        }
+    }
+}
+
+impl From<BevyTridentConeMeshBuilder> for Mesh {
+    fn from(builder: BevyTridentConeMeshBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<BevyTridentCone> for Mesh {
+    fn from(cone: BevyTridentCone) -> Self {
+        cone.mesh().build()
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::{
+    prelude::*,
+    render::{view::RenderLayers, camera::ClearColorConfig},
+};
+
+/// Add to a camera to draw [`TridentOverlay`] entities over its scene.
+/// Spawns a child camera rendering only layer [`TridentOverlay::LAYER`] at order of this camera
+/// plus [`TridentOverlay::ORDER_OFFSET`], keep that order free and below 100, which is taken by the viewcube.
+#[derive(Component, Default)]
+pub struct TridentOverlayCamera;
+
+/// Add to a trident to draw it on top of scene geometry, still depth sorted against itself.
+/// Needs a camera with [`TridentOverlayCamera`].
+#[derive(Component, Default)]
+pub struct TridentOverlay;
+
+impl TridentOverlay {
+    /// Render layer of overlay tridents, 13 is used by viewcube.
+    pub const LAYER: u8 = 14;
+    /// Order of overlay camera relative to the camera it draws over.
+    pub const ORDER_OFFSET: isize = 1;
+}
+
+/// Camera spawned for a [`TridentOverlayCamera`], child of it.
+#[derive(Component)]
+pub(crate) struct OverlayCamera;
+
+pub(crate) fn spawn_overlay_camera(
+    mut commands: Commands,
+    cameras: Query<(Entity, &Camera, &Projection), Added<TridentOverlayCamera>>,
+) {
+    for (entity, camera, projection) in cameras.iter() {
+        let order = camera.order + TridentOverlay::ORDER_OFFSET;
+        if order >= crate::SmallView::ORDER {
+            warn!("Trident overlay camera order {order} is not below viewcube camera order {}", crate::SmallView::ORDER);
+        }
+        commands.entity(entity).with_children(|builder| {
+            builder.spawn((
+                Camera3dBundle {
+                    camera: Camera {
+                        order,
+                        hdr: camera.hdr,
+                        viewport: camera.viewport.clone(),
+                        clear_color: ClearColorConfig::None,
+                        ..Default::default()
+                    },
+                    camera_3d: Camera3d {
+                        depth_load_op: bevy::core_pipeline::core_3d::Camera3dDepthLoadOp::Clear(0.),
+                        ..default()
+                    },
+                    projection: projection.clone(),
+                    ..default()
+                },
+                RenderLayers::layer(TridentOverlay::LAYER),
+                OverlayCamera,
+            )).with_children(|builder| {
+                builder.spawn((
+                    DirectionalLightBundle::default(),
+                    RenderLayers::layer(TridentOverlay::LAYER),
+                ));
+            });
+        });
+    }
+}
+
+/// Keep projection and viewport of overlay camera same as its parent.
+#[allow(clippy::type_complexity)]
+pub(crate) fn sync_overlay_camera(
+    mut overlays: Query<(&Parent, &mut Camera, &mut Projection), With<OverlayCamera>>,
+    cameras: Query<(&Camera, &Projection), (Without<OverlayCamera>, Or<(Changed<Camera>, Changed<Projection>)>)>,
+) {
+    for (parent, mut overlay, mut overlay_projection) in overlays.iter_mut() {
+        let Ok((camera, projection)) = cameras.get(parent.get()) else {
+            continue;
+        };
+        overlay.is_active = camera.is_active;
+        overlay.viewport = camera.viewport.clone();
+        *overlay_projection = projection.clone();
+    }
+}
+
+/// Render layers of a [`TridentOverlay`] entity before it moved to the overlay layer, restored on removal.
+#[derive(Component)]
+pub(crate) struct OverlayPreviousLayers(Option<RenderLayers>);
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn add_overlay_layer(
+    mut commands: Commands,
+    tridents: Query<(Entity, Option<&RenderLayers>), (Added<TridentOverlay>, Without<OverlayPreviousLayers>)>,
+    previous: Query<&OverlayPreviousLayers, Without<TridentOverlay>>,
+    mut removed: RemovedComponents<TridentOverlay>,
+) {
+    // Removed and added again before this ran keeps layers saved first.
+    for entity in removed.read() {
+        let Ok(OverlayPreviousLayers(layers)) = previous.get(entity) else {
+            continue;
+        };
+        let mut entity = commands.entity(entity);
+        entity.remove::<OverlayPreviousLayers>();
+        match layers {
+            Some(layers) => entity.insert(*layers),
+            None => entity.remove::<RenderLayers>(),
+        };
+    }
+    for (entity, layers) in tridents.iter() {
+        commands.entity(entity).insert((
+            RenderLayers::layer(TridentOverlay::LAYER),
+            OverlayPreviousLayers(layers.copied()),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removing_overlay_restores_layers() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_systems(Update, add_overlay_layer);
+        let layered = app.world.spawn((TridentOverlay, RenderLayers::layer(3))).id();
+        let plain = app.world.spawn(TridentOverlay).id();
+        app.update();
+        for entity in [layered, plain] {
+            assert_eq!(app.world.get::<RenderLayers>(entity), Some(&RenderLayers::layer(TridentOverlay::LAYER)));
+            app.world.entity_mut(entity).remove::<TridentOverlay>();
+        }
+        app.update();
+        assert_eq!(app.world.get::<RenderLayers>(layered), Some(&RenderLayers::layer(3)));
+        assert_eq!(app.world.get::<RenderLayers>(plain), None);
+        assert!(app.world.get::<OverlayPreviousLayers>(layered).is_none());
+    }
+}
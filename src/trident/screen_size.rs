@@ -0,0 +1,99 @@
+use bevy::{
+    prelude::*,
+    render::view::RenderLayers,
+    transform::helper::TransformHelper,
+};
+
+/// Rescale entity every frame so that one unit of it spans `pixels` on screen.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ConstantScreenSize {
+    pub pixels: f32,
+    /// Camera viewing entity, `None` picks the camera sharing render layers with entity.
+    pub camera: Option<Entity>,
+}
+
+impl Default for ConstantScreenSize {
+    fn default() -> Self {
+        Self {
+            pixels: 100.0,
+            camera: None,
+        }
+    }
+}
+
+/// World size of a pixel at `position`.
+fn pixel_size(camera: &Camera, projection: &Projection, camera_transform: &GlobalTransform, position: Vec3) -> Option<f32> {
+    let height = camera.logical_viewport_size()?.y;
+    let visible = match projection {
+        Projection::Perspective(perspective) => {
+            let depth = (position - camera_transform.translation()).dot(camera_transform.forward());
+            2.0 * depth.max(perspective.near) * (perspective.fov / 2.0).tan()
+        },
+        Projection::Orthographic(orthographic) => orthographic.area.height(),
+    };
+    Some(visible / height)
+}
+
+/// Cameras an entity can be measured against.
+pub(crate) type ScreenCameras<'w, 's> = Query<'w, 's, (Entity, &'static Camera, &'static Projection, Option<&'static RenderLayers>)>;
+
+/// Global transform of `entity` and world size of a pixel at it, seen by `camera`
+/// or the highest order active camera sharing `layers`.
+pub(crate) fn measure(
+    entity: Entity, layers: Option<&RenderLayers>, camera: Option<Entity>,
+    cameras: &ScreenCameras, helper: &TransformHelper,
+) -> Option<(GlobalTransform, f32)> {
+    let layers = layers.copied().unwrap_or_default();
+    let (camera_entity, camera, projection, _) = match camera {
+        Some(camera) => cameras.get(camera).ok(),
+        None => cameras.iter()
+            .filter(|(_, camera, _, camera_layers)|
+                camera.is_active && camera_layers.copied().unwrap_or_default().intersects(&layers)
+            )
+            .max_by_key(|(_, camera, _, _)| camera.order),
+    }?;
+    let global = helper.compute_global_transform(entity).ok()?;
+    let camera_transform = helper.compute_global_transform(camera_entity).ok()?;
+    let pixel = pixel_size(camera, projection, &camera_transform, global.translation())?;
+    Some((global, pixel))
+}
+
+/// Transform helper reads every `Transform`, so scales are measured before they are written.
+pub(crate) fn constant_screen_size(
+    entities: Query<(Entity, &ConstantScreenSize, Option<&RenderLayers>)>,
+    cameras: ScreenCameras,
+    mut params: ParamSet<(TransformHelper, Query<&mut Transform, With<ConstantScreenSize>>)>,
+) {
+    let helper = params.p0();
+    let rescales = entities.iter().filter_map(|(entity, screen_size, layers)| {
+        let (global, pixel) = measure(entity, layers, screen_size.camera, &cameras, &helper)?;
+        // Scale of parents is kept, only own scale changes.
+        let world_scale = global.compute_transform().scale;
+        if world_scale.cmpeq(Vec3::ZERO).any() {
+            return None;
+        }
+        Some((entity, Vec3::splat(screen_size.pixels * pixel) / world_scale))
+    }).collect::<Vec<_>>();
+    let mut transforms = params.p1();
+    for (entity, rescale) in rescales {
+        if let Ok(mut transform) = transforms.get_mut(entity) {
+            transform.scale *= rescale;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_without_conflicting_transform_access() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_systems(Update, constant_screen_size);
+        app.world.spawn(Camera3dBundle::default());
+        let entity = app.world.spawn((TransformBundle::default(), ConstantScreenSize::default())).id();
+        app.update();
+        // Headless camera has no viewport to measure, so scale is kept.
+        assert_eq!(app.world.get::<Transform>(entity).unwrap().scale, Vec3::ONE);
+    }
+}
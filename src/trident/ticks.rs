@@ -0,0 +1,95 @@
+use bevy::{
+    math::Vec3,
+    render::color::Color,
+};
+
+use super::{
+    gen_cylinder,
+    merge_buffers,
+    BevyTridentArrow,
+    BevyTridentAxis,
+    BevyTridentMeshSettings,
+    MeshBuffers,
+};
+
+/// Graduation rings along shafts, so a trident doubles as a scale reference.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BevyTridentTicks {
+    /// Distance between ticks along shaft.
+    pub spacing: f32,
+    /// Every n-th tick is major, none when 0.
+    pub major_every: usize,
+    /// Radius of minor and major ticks relative to shaft radius.
+    pub radius_scale: (f32, f32),
+    /// Width along shaft relative to shaft radius.
+    pub width_scale: f32,
+    /// `None` uses arrow color.
+    pub color: Option<Color>,
+}
+
+impl Default for BevyTridentTicks {
+    fn default() -> Self {
+        Self::new(1.0, 10)
+    }
+}
+
+impl BevyTridentTicks {
+    /// Most ticks along one shaft, denser spacing is widened to fit.
+    pub const MAX_TICKS: usize = 256;
+
+    pub const fn new(spacing: f32, major_every: usize) -> Self {
+        Self {
+            spacing,
+            major_every,
+            radius_scale: (1.5, 2.2),
+            width_scale: 0.5,
+            color: Some(Color::WHITE),
+        }
+    }
+
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Distances of ticks from origin up to `length` and whether each is major, at most [`Self::MAX_TICKS`].
+    pub fn positions(&self, length: f32) -> Vec<(f32, bool)> {
+        if self.spacing <= 0.0 || length <= 0.0 {
+            return vec![];
+        }
+        let spacing = self.spacing.max(length / Self::MAX_TICKS as f32);
+        (1..=Self::MAX_TICKS).map(|i| (i as f32 * spacing, self.major_every > 0 && i % self.major_every == 0))
+            .take_while(|(distance, _)| *distance <= length)
+            .collect()
+    }
+
+    /// Width of ticks along shaft of `arrow`.
+    fn width(&self, arrow: &BevyTridentArrow) -> f32 {
+        arrow.tail_radius * self.width_scale
+    }
+
+    /// Ticks fitting whole on shaft of `arrow`, shared by meshes and labels.
+    pub(crate) fn on_shaft(&self, arrow: &BevyTridentArrow) -> Vec<(f32, bool)> {
+        self.positions(arrow.tail_length - self.width(arrow) / 2.0)
+    }
+}
+
+impl BevyTridentAxis {
+    /// Ticks along shaft of positive arrow along world axis `dir`.
+    pub(crate) fn gen_ticks(&self, dir: usize, settings: &BevyTridentMeshSettings) -> Option<MeshBuffers> {
+        let ticks = self.ticks?;
+        let arrow = &self.axises[dir];
+        let mut axis = Vec3::ZERO;
+        axis[dir] = 1.0;
+        let width = ticks.width(arrow);
+        let color = ticks.color.unwrap_or(self.color(dir));
+        let parts = ticks.on_shaft(arrow).into_iter().map(|(at, major)| {
+            let scale = if major { ticks.radius_scale.1 } else { ticks.radius_scale.0 };
+            gen_cylinder(
+                axis, at - width / 2.0, at + width / 2.0, arrow.tail_radius * scale, arrow.tail_subdivisions,
+                color, settings,
+            )
+        }).collect::<Vec<_>>();
+        (!parts.is_empty()).then(|| merge_buffers(parts))
+    }
+}
@@ -1,35 +1,49 @@
 mod simple_viewcube;
 mod powerful_viewcube;
+mod fade;
+mod outline;
+mod compass;
+mod custom_viewcube;
 use bevy::{
     app::{
-        Plugin, Startup, Update
+        Plugin, PostUpdate, Startup, Update
     },
     ecs::{
         component::Component,
         entity::Entity,
-        query::With,
+        event::{Event, EventReader},
+        query::{With, Without},
         schedule::IntoSystemConfigs,
         system::{
             Commands,
-            ParamSet,
-            Query
+            Query,
+            Res,
+            Resource
         }
     },
     math::{
         UVec2,
         Vec3
     },
+    gizmos::AppGizmoBuilder,
     prelude::default,
     render::camera::Camera,
-    transform::components::Transform,
+    transform::{
+        components::Transform,
+        TransformSystem
+    },
     window::Window
 };
 use bevy_panorbit_camera::PanOrbitCamera;
 
-use crate::{PI_2, PI_4, PI_4_3};
+use crate::{
+    trident::BevyTridentLabels,
+    PI_2, PI_4, PI_4_3
+};
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-pub(crate) enum CubePart {
+/// Parts of viewcube, named by the view they snap camera to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CubePart {
     // Face
     Front, Back, Left, Right, Top, Bottom,
     // Edge
@@ -41,31 +55,172 @@ pub(crate) enum CubePart {
     BackLeftTop, BackLeftBottom, BackRightTop, BackRightBottom,
 }
 
-#[derive(Default)]
+impl CubePart {
+    /// `alpha` and `beta` of orbit camera viewing this part.
+    pub fn orientation(&self) -> (f32, f32) {
+        match self {
+            CubePart::Right => (PI_2, 0.0),
+            CubePart::Left => (-PI_2, 0.0),
+            CubePart::Top => (0.0, PI_2),
+            CubePart::Bottom => (0.0, -PI_2),
+            CubePart::Front => (0.0, 0.0),
+            CubePart::Back => (crate::PI, 0.0),
+            CubePart::FrontTop => (0.0, PI_4),
+            CubePart::FrontBottom => (0.0, -PI_4),
+            CubePart::BackTop => (crate::PI, PI_4),
+            CubePart::BackBottom => (crate::PI, -PI_4),
+            CubePart::LeftTop => (-PI_2, PI_4),
+            CubePart::LeftBottom => (-PI_2, -PI_4),
+            CubePart::RightTop => (PI_2, PI_4),
+            CubePart::RightBottom => (PI_2, -PI_4),
+            CubePart::FrontLeft => (-PI_4, 0.0),
+            CubePart::FrontRight => (PI_4, 0.0),
+            CubePart::BackLeft => (-PI_4_3, 0.0),
+            CubePart::BackRight => (PI_4_3, 0.0),
+            CubePart::FrontLeftTop => (-PI_4, PI_4),
+            CubePart::FrontLeftBottom => (-PI_4, -PI_4),
+            CubePart::FrontRightTop => (PI_4, PI_4),
+            CubePart::FrontRightBottom => (PI_4, -PI_4),
+            CubePart::BackLeftTop => (-PI_4_3, PI_4),
+            CubePart::BackLeftBottom => (-PI_4_3, -PI_4),
+            CubePart::BackRightTop => (PI_4_3, PI_4),
+            CubePart::BackRightBottom => (PI_4_3, -PI_4),
+        }
+    }
+}
+
+/// Orientation to snap camera to when a part of viewcube is clicked.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ViewcubeTarget {
+    Part(CubePart),
+    /// `alpha` and `beta` of orbit camera.
+    Orbit { alpha: f32, beta: f32 },
+}
+
+impl ViewcubeTarget {
+    pub fn orientation(&self) -> (f32, f32) {
+        match self {
+            ViewcubeTarget::Part(part) => part.orientation(),
+            ViewcubeTarget::Orbit { alpha, beta } => (*alpha, *beta),
+        }
+    }
+}
+
+impl From<CubePart> for ViewcubeTarget {
+    fn from(part: CubePart) -> Self {
+        ViewcubeTarget::Part(part)
+    }
+}
+
+pub use fade::ViewcubeFade;
+pub use outline::ViewcubeOutline;
+pub use compass::ViewcubeCompass;
+pub use custom_viewcube::{
+    CustomViewcube,
+    CustomViewcubePart,
+    CustomViewcubeScene
+};
+
 pub struct BevyViewCubePlugin {
     pub use_powerful_viewcube: bool,
+    /// Opacity of viewcube when hovered or without fading, 1.0 is opaque.
+    pub opacity: f32,
+    /// Fade viewcube to `idle_opacity` after no pointer interaction.
+    pub fade: Option<ViewcubeFade>,
+    /// Draw lines along edges and face borders.
+    pub outline: Option<ViewcubeOutline>,
+    /// Compass ring with clickable cardinal points below viewcube.
+    pub compass: Option<ViewcubeCompass>,
+    /// Labels at tips of trident inside viewcube.
+    /// On a custom viewcube they are placed around its center, set `positions` to fit the geometry.
+    pub trident_labels: Option<BevyTridentLabels>,
+    /// Replace built-in viewcube with user geometry, `use_powerful_viewcube` is ignored.
+    pub custom: Option<CustomViewcube>,
+}
+
+impl Default for BevyViewCubePlugin {
+    fn default() -> Self {
+        Self {
+            use_powerful_viewcube: false,
+            opacity: 1.0,
+            fade: None,
+            outline: None,
+            compass: None,
+            trident_labels: None,
+            custom: None,
+        }
+    }
 }
 
 impl Plugin for BevyViewCubePlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        let setup = if self.use_powerful_viewcube {
-            powerful_viewcube::setup
+        if let Some(custom) = &self.custom {
+            app
+            .insert_resource(custom.clone())
+            .add_systems(Startup, (custom_viewcube::setup, crate::create_small_view).chain())
+            .add_systems(Update, custom_viewcube::prepare_scene);
         } else {
-            simple_viewcube::setup
-        };
+            let setup = if self.use_powerful_viewcube {
+                powerful_viewcube::setup
+            } else {
+                simple_viewcube::setup
+            };
+            app.add_systems(Startup, (setup,crate::create_small_view).chain());
+        }
         app
-        .add_systems(Startup, (setup,crate::create_small_view).chain())
-        .add_systems(Update, update_view)
+        .insert_resource(fade::ViewcubeOpacity::new(self.opacity, self.fade))
+        .add_event::<ViewcubeHit>()
+        .add_systems(Update, (layout_small_view, update_view))
         .add_systems(Update, viewcube_hit)
+        .add_systems(Update, fade::fade_viewcube.after(update_view))
         ;
+        if let Some(outline) = self.outline {
+            app
+            .insert_gizmo_group(outline::ViewcubeGizmos, outline::ViewcubeGizmos::config(outline.width))
+            .insert_resource(outline)
+            .add_systems(Update, outline::build_outline)
+            .add_systems(
+                PostUpdate,
+                outline::draw_outline.after(TransformSystem::TransformPropagate)
+            );
+        }
+        if let Some(labels) = &self.trident_labels {
+            if !app.is_plugin_added::<crate::trident::labels::TridentLabelPlugin>() {
+                app.add_plugins(crate::trident::labels::TridentLabelPlugin);
+            }
+            app
+            .insert_resource(ViewcubeTridentLabels(labels.clone()))
+            .add_systems(Startup, label_trident.after(crate::create_small_view));
+        }
+        if let Some(compass) = self.compass {
+            if !app.is_plugin_added::<crate::label::BillboardLabelPlugin>() {
+                app.add_plugins(crate::label::BillboardLabelPlugin);
+            }
+            app
+            .insert_resource(compass)
+            .add_systems(Startup, compass::spawn_compass.after(crate::create_small_view))
+            .add_systems(Update, (compass::compass_hit, compass::compass_drag, compass::compass_click));
+        }
     }
 }
 
 #[derive(Component)]
 pub(crate) struct ViewcubeCenter;
 
+/// Snap orbit camera to target, sent when a part of viewcube is clicked.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ViewcubeHit(pub ViewcubeTarget);
+
+/// Trident inside viewcube, or center of custom viewcube, labeled with `trident_labels`.
+#[derive(Component)]
+pub(crate) struct ViewcubeTrident;
+
+#[derive(Resource)]
+pub(crate) struct ViewcubeTridentLabels(BevyTridentLabels);
+
+/// Pickable part of viewcube.
 #[derive(Component)]
-pub(crate) struct ViewcubeHit(pub CubePart);
+pub(crate) struct ViewcubePart;
 
 #[macro_export]
 macro_rules! generate_viewcube_face {
@@ -77,23 +232,24 @@ macro_rules! generate_viewcube_face {
             ..Default::default()
         },
         RenderLayers::layer(13),
+        $crate::viewcube::ViewcubePart,
         PickableBundle::default(),
-        On::<Pointer<Click>>::commands_mut(|event, commands| {
-            commands.entity(event.target).insert($component);
+        On::<Pointer<Click>>::commands_mut(move |_event, commands| {
+            commands.add(move |world: &mut World| {
+                world.send_event($component);
+            });
         }))
     };
 }
 
-pub(crate) fn update_view(
+/// Place small view in lower left corner of window.
+pub(crate) fn layout_small_view(
     windows: Query<&Window>,
     mut camera: Query<&mut Camera, With<crate::SmallView>>,
-    mut transform_query: ParamSet<(
-        Query<&mut Transform, With<ViewcubeCenter>>,
-        Query<&Transform, (With<PanOrbitCamera>, With<crate::ViewcubeBinding>)>,
-    )>
 ) {
-    let window: &Window = windows.single();
-    let mut cam = camera.single_mut();
+    let (Ok(window), Ok(mut cam)) = (windows.get_single(), camera.get_single_mut()) else {
+        return;
+    };
     cam.viewport = Some(bevy::render::camera::Viewport {
         physical_position: UVec2::new(
             0, (window.physical_height() as f32 * 0.6) as u32
@@ -104,64 +260,148 @@ pub(crate) fn update_view(
         ),
         ..default()
     });
-    let transform;
-    {
-        let orbit_cameras = transform_query.p1();
-        transform = if let Ok(tr) = orbit_cameras.get_single() {
-            tr.clone()
-        } else {
-            Transform::IDENTITY
-        }
+}
+
+/// Rotate viewcube opposite to bound camera.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_view(
+    mut centers: Query<&mut Transform, With<ViewcubeCenter>>,
+    orbit_cameras: Query<&Transform, (With<PanOrbitCamera>, With<crate::ViewcubeBinding>, Without<ViewcubeCenter>)>,
+) {
+    let transform = orbit_cameras.get_single().copied().unwrap_or(Transform::IDENTITY);
+    for mut center in centers.iter_mut() {
+        center.rotation = transform.rotation.inverse();
     }
-    let mut trident = transform_query.p0();
-    let mut trident_transform = trident.single_mut();
-    trident_transform.rotation = transform.rotation.inverse();
+}
 
+fn label_trident(
+    mut commands: Commands,
+    labels: Res<ViewcubeTridentLabels>,
+    tridents: Query<Entity, With<ViewcubeTrident>>,
+) {
+    for trident in tridents.iter() {
+        commands.entity(trident).insert(labels.0.clone());
+    }
 }
 
+/// Snap camera bound to viewcube to each hit.
+/// Hits sent in the same frame are applied in order, so the last one decides the target.
 pub(crate) fn viewcube_hit(
-    mut commands: Commands,
-    entity: Query<(Entity, &ViewcubeHit)>,
-    mut camera: Query<&mut PanOrbitCamera>,
+    mut hits: EventReader<ViewcubeHit>,
+    mut camera: Query<&mut PanOrbitCamera, With<crate::ViewcubeBinding>>,
 ) {
-    if entity.is_empty() {
-        return;
+    for hit in hits.read() {
+        let (alpha, beta) = hit.0.orientation();
+        for mut orbit_camera in camera.iter_mut() {
+            orbit_camera.target_focus = Vec3::ZERO;
+            orbit_camera.target_alpha = alpha;
+            orbit_camera.target_beta = beta;
+        }
     }
-    let (item, dir) = entity.single();
-    commands.entity(item).remove::<ViewcubeHit>();
-
-    let (alpha, beta) = match dir.0 {
-        CubePart::Right => (PI_2, 0.0),
-        CubePart::Left => (-PI_2, 0.0),
-        CubePart::Top => (0.0, PI_2),
-        CubePart::Bottom => (0.0, -PI_2),
-        CubePart::Front => (0.0, 0.0),
-        CubePart::Back => (crate::PI, 0.0),
-        CubePart::FrontTop => (0.0, PI_4),
-        CubePart::FrontBottom => (0.0, -PI_4),
-        CubePart::BackTop => (crate::PI, PI_4),
-        CubePart::BackBottom => (crate::PI, -PI_4),
-        CubePart::LeftTop => (-PI_2, PI_4),
-        CubePart::LeftBottom => (-PI_2, -PI_4),
-        CubePart::RightTop => (PI_2, PI_4),
-        CubePart::RightBottom => (PI_2, -PI_4),
-        CubePart::FrontLeft => (-PI_4, 0.0),
-        CubePart::FrontRight => (PI_4, 0.0),
-        CubePart::BackLeft => (-PI_4_3, 0.0),
-        CubePart::BackRight => (PI_4_3, 0.0),
-        CubePart::FrontLeftTop => (-PI_4, PI_4),
-        CubePart::FrontLeftBottom => (-PI_4, -PI_4),
-        CubePart::FrontRightTop => (PI_4, PI_4),
-        CubePart::FrontRightBottom => (PI_4, -PI_4),
-        CubePart::BackLeftTop => (-PI_4_3, PI_4),
-        CubePart::BackLeftBottom => (-PI_4_3, -PI_4),
-        CubePart::BackRightTop => (PI_4_3, PI_4),
-        CubePart::BackRightBottom => (PI_4_3, -PI_4),
-    };
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{input::InputPlugin, prelude::*};
+    use bevy_panorbit_camera::{PanOrbitCameraPlugin, PanOrbitCameraSystemSet};
+
+    use super::*;
 
-    let mut orbit_camera = camera.single_mut();
+    /// Headless app with a bound orbit camera and a viewcube center.
+    fn app() -> (App, Entity, Entity) {
+        let mut app = App::new();
+        app
+        .add_plugins((MinimalPlugins, InputPlugin, PanOrbitCameraPlugin))
+        .add_event::<ViewcubeHit>()
+        .add_systems(Update, (
+            viewcube_hit.before(PanOrbitCameraSystemSet),
+            update_view.after(PanOrbitCameraSystemSet),
+        ));
+        let camera = app.world.spawn((
+            Camera::default(),
+            Projection::default(),
+            Transform::from_xyz(0.0, 0.0, 5.0),
+            PanOrbitCamera {
+                orbit_smoothness: 0.0,
+                ..default()
+            },
+            crate::ViewcubeBinding,
+        )).id();
+        let center = app.world.spawn((Transform::IDENTITY, ViewcubeCenter)).id();
+        app.update();
+        (app, camera, center)
+    }
+
+    /// Simulate a click on viewcube and step the app.
+    fn click(app: &mut App, target: impl Into<ViewcubeTarget>) {
+        app.world.send_event(ViewcubeHit(target.into()));
+        app.update();
+    }
+
+    /// Direction from focus to orbit camera.
+    fn orbit_direction(alpha: f32, beta: f32) -> Vec3 {
+        Vec3::new(alpha.sin() * beta.cos(), beta.sin(), alpha.cos() * beta.cos())
+    }
+
+    #[test]
+    fn click_snaps_camera_to_part() {
+        let (mut app, camera, _) = app();
+        let unbound = app.world.spawn((
+            Camera::default(),
+            Projection::default(),
+            Transform::from_xyz(0.0, 0.0, 5.0),
+            PanOrbitCamera {
+                orbit_smoothness: 0.0,
+                ..default()
+            },
+        )).id();
+        app.update();
+        let unbound_start = *app.world.get::<Transform>(unbound).unwrap();
+        for part in [CubePart::Right, CubePart::Back, CubePart::FrontTop, CubePart::BackLeftBottom] {
+            click(&mut app, part);
+            let (alpha, beta) = part.orientation();
+            let transform = app.world.get::<Transform>(camera).unwrap();
+            let expected = orbit_direction(alpha, beta);
+            assert!(transform.translation.normalize().abs_diff_eq(expected, 1e-4), "{part:?} at {}", transform.translation);
+            assert!(transform.forward().abs_diff_eq(-expected, 1e-4), "{part:?} looking {}", *transform.forward());
+            assert_eq!(*app.world.get::<Transform>(unbound).unwrap(), unbound_start, "unbound camera moved");
+        }
+    }
+
+    #[test]
+    fn click_snaps_camera_to_orbit() {
+        let (mut app, camera, _) = app();
+        click(&mut app, ViewcubeTarget::Orbit { alpha: 0.3, beta: -0.2 });
+        let orbit = app.world.get::<PanOrbitCamera>(camera).unwrap();
+        assert_eq!((orbit.target_alpha, orbit.target_beta), (0.3, -0.2));
+        assert_eq!(orbit.target_focus, Vec3::ZERO);
+    }
+
+    #[test]
+    fn viewcube_rotates_against_camera() {
+        let (mut app, camera, center) = app();
+        click(&mut app, CubePart::FrontRightTop);
+        let camera = app.world.get::<Transform>(camera).unwrap().rotation;
+        let center = app.world.get::<Transform>(center).unwrap().rotation;
+        assert!(center.abs_diff_eq(camera.inverse(), 1e-5));
+        assert!(!center.abs_diff_eq(Quat::IDENTITY, 1e-3));
+    }
 
-    orbit_camera.target_focus = Vec3::ZERO;
-    orbit_camera.target_alpha = alpha;
-    orbit_camera.target_beta = beta;
-}
\ No newline at end of file
+    #[test]
+    fn trident_labels_coexist_with_trident_plugin() {
+        for viewcube_first in [true, false] {
+            let mut app = App::new();
+            // Enough of rendering for the on top material of `BevyTridentPlugin`.
+            app.add_plugins((MinimalPlugins, AssetPlugin::default())).init_asset::<Shader>();
+            let viewcube = BevyViewCubePlugin {
+                trident_labels: Some(default()),
+                ..default()
+            };
+            if viewcube_first {
+                app.add_plugins((viewcube, crate::trident::BevyTridentPlugin));
+            } else {
+                app.add_plugins((crate::trident::BevyTridentPlugin, viewcube));
+            }
+        }
+    }
+}
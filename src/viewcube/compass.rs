@@ -0,0 +1,300 @@
+use bevy::{
+    prelude::*,
+    render::{
+        mesh::Indices,
+        render_asset::RenderAssetUsages,
+        render_resource::PrimitiveTopology,
+        view::RenderLayers
+    },
+};
+use bevy_mod_picking::{
+    pointer::{InputPress, PressDirection},
+    prelude::*,
+};
+use bevy_panorbit_camera::PanOrbitCamera;
+
+use crate::label::BillboardLabel;
+
+use super::{
+    ViewcubeCenter,
+    ViewcubePart,
+};
+
+/// Compass ring with cardinal points below viewcube.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct ViewcubeCompass {
+    /// Direction of north in world, only horizontal part is used.
+    pub north: Vec3,
+    pub color: Color,
+    pub label_color: Color,
+}
+
+impl Default for ViewcubeCompass {
+    fn default() -> Self {
+        Self {
+            north: Vec3::NEG_Z,
+            color: Color::GRAY,
+            label_color: Color::WHITE,
+        }
+    }
+}
+
+impl ViewcubeCompass {
+    /// Rotation from compass local space, where north is -Z, to world.
+    pub(crate) fn rotation(&self) -> Quat {
+        Quat::from_rotation_y((-self.north.x).atan2(-self.north.z))
+    }
+}
+
+const RING_HEIGHT: f32 = -0.5;
+const RING_INNER: f32 = 0.62;
+const RING_OUTER: f32 = 0.72;
+const RING_SEGMENTS: usize = 64;
+const MARKER_LENGTH: f32 = 0.12;
+const MARKER_WIDTH: f32 = 0.07;
+const LABEL_RADIUS: f32 = 0.95;
+/// Pointer moved less than this is still a click, in logical pixels.
+const CLICK_DISTANCE: f32 = 3.0;
+
+#[derive(Component)]
+pub(crate) struct CompassRing;
+
+/// Heading clicked, as `alpha` of orbit camera.
+#[derive(Component)]
+pub(crate) struct CompassHit(pub f32);
+
+/// Flat ring in XZ plane facing +Y.
+fn ring_mesh() -> Mesh {
+    let mut positions = Vec::with_capacity(2 * (RING_SEGMENTS + 1));
+    let mut uvs = Vec::with_capacity(2 * (RING_SEGMENTS + 1));
+    let mut indices = Vec::with_capacity(6 * RING_SEGMENTS);
+    for i in 0..=RING_SEGMENTS {
+        let t = i as f32 / RING_SEGMENTS as f32;
+        let (sin, cos) = (t * std::f32::consts::TAU).sin_cos();
+        positions.push([cos * RING_INNER, 0.0, sin * RING_INNER]);
+        positions.push([cos * RING_OUTER, 0.0, sin * RING_OUTER]);
+        uvs.push([t, 0.0]);
+        uvs.push([t, 1.0]);
+    }
+    for i in 0..RING_SEGMENTS as u32 {
+        let (inner, outer) = (2 * i, 2 * i + 1);
+        let (inner_next, outer_next) = (inner + 2, outer + 2);
+        indices.extend([inner, outer_next, outer, inner, inner_next, outer_next]);
+    }
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    mesh.insert_indices(Indices::U32(indices));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh
+}
+
+/// Triangle on outside of ring pointing to `dir`.
+fn marker_mesh(dir: Vec3) -> Mesh {
+    let base = dir * RING_OUTER;
+    let side = dir.cross(Vec3::Y) * MARKER_WIDTH;
+    let positions = vec![
+        (dir * (RING_OUTER + MARKER_LENGTH)).to_array(),
+        (base - side).to_array(),
+        (base + side).to_array(),
+    ];
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    mesh.insert_indices(Indices::U32(vec![0, 1, 2]));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0f32, 1.0, 0.0]; 3]);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.5f32, 1.0], [0.0, 0.0], [1.0, 0.0]]);
+    mesh
+}
+
+pub(crate) fn spawn_compass(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    compass: Res<ViewcubeCompass>,
+    center: Query<Entity, With<ViewcubeCenter>>,
+    camera: Query<Entity, With<crate::SmallView>>,
+) {
+    let rotation = compass.rotation();
+    let mut material = |color: Color| materials.add(StandardMaterial {
+        base_color: color,
+        // Visible from below as well.
+        cull_mode: None,
+        double_sided: true,
+        ..Default::default()
+    });
+    let ring_material = material(compass.color);
+    let north_material = material(Color::RED);
+
+    let root = commands.spawn((
+        SpatialBundle::from_transform(Transform {
+            translation: Vec3::Y * RING_HEIGHT,
+            rotation,
+            ..Default::default()
+        }),
+        RenderLayers::layer(13),
+    )).with_children(|builder| {
+        builder.spawn((
+            MaterialMeshBundle {
+                mesh: meshes.add(ring_mesh()),
+                material: ring_material.clone(),
+                ..Default::default()
+            },
+            RenderLayers::layer(13),
+            ViewcubePart,
+            CompassRing,
+            PickableBundle::default(),
+        ));
+        for (dir, material) in [
+            (Vec3::NEG_Z, north_material),
+            (Vec3::X, ring_material.clone()),
+            (Vec3::Z, ring_material.clone()),
+            (Vec3::NEG_X, ring_material.clone()),
+        ] {
+            // Camera faces heading.
+            let alpha = heading_alpha(rotation.mul_vec3(dir));
+            builder.spawn((
+                MaterialMeshBundle {
+                    mesh: meshes.add(marker_mesh(dir)),
+                    material,
+                    ..Default::default()
+                },
+                RenderLayers::layer(13),
+                ViewcubePart,
+                PickableBundle::default(),
+                On::<Pointer<Click>>::commands_mut(move |event, commands| {
+                    commands.entity(event.target).insert(CompassHit(alpha));
+                }),
+            ));
+        }
+    }).id();
+    commands.entity(center.single()).add_child(root);
+
+    let camera = camera.single();
+    for (text, dir) in [("N", Vec3::NEG_Z), ("E", Vec3::X), ("S", Vec3::Z), ("W", Vec3::NEG_X)] {
+        commands.spawn((
+            BillboardLabel::text_bundle(text, compass.label_color, 16.0),
+            BillboardLabel {
+                anchor: root,
+                offset: dir * LABEL_RADIUS,
+            },
+            TargetCamera(camera),
+        ));
+    }
+}
+
+/// Nearest angle equivalent to `target` from `current`.
+pub(crate) fn nearest_angle(current: f32, target: f32) -> f32 {
+    let delta = (target - current).rem_euclid(std::f32::consts::TAU);
+    if delta > std::f32::consts::PI {
+        current + delta - std::f32::consts::TAU
+    } else {
+        current + delta
+    }
+}
+
+pub(crate) fn compass_hit(
+    mut commands: Commands,
+    hits: Query<(Entity, &CompassHit)>,
+    mut camera: Query<&mut PanOrbitCamera, With<crate::ViewcubeBinding>>,
+) {
+    for (item, hit) in hits.iter() {
+        commands.entity(item).remove::<CompassHit>();
+        let Ok(mut orbit_camera) = camera.get_single_mut() else {
+            continue;
+        };
+        // Keep elevation, only turn heading.
+        let current = orbit_camera.target_alpha;
+        orbit_camera.target_alpha = nearest_angle(current, hit.0);
+    }
+}
+
+/// Camera heading facing `dir`, as `alpha` of orbit camera.
+fn heading_alpha(dir: Vec3) -> f32 {
+    (-dir.x).atan2(-dir.z)
+}
+
+/// Dragging compass ring turns heading only.
+pub(crate) fn compass_drag(
+    mut downs: EventReader<Pointer<Down>>,
+    mut drags: EventReader<Pointer<Drag>>,
+    mut presses: EventReader<InputPress>,
+    mut holding: Local<bool>,
+    rings: Query<&GlobalTransform, With<CompassRing>>,
+    small_view: Query<(&Camera, &GlobalTransform), With<crate::SmallView>>,
+    mut camera: Query<&mut PanOrbitCamera, With<crate::ViewcubeBinding>>,
+) {
+    // Drain readers every frame, events left unread would end or turn a later drag.
+    let grabbed = downs.read().filter(|down| rings.contains(down.target)).count() > 0;
+    let released = presses.read().filter(|press| press.direction == PressDirection::Up).count() > 0;
+    let drags = drags.read().collect::<Vec<_>>();
+    let Ok(mut orbit_camera) = camera.get_single_mut() else {
+        return;
+    };
+
+    // Stop orbiting by pointer while holding ring.
+    if grabbed {
+        *holding = true;
+        orbit_camera.enabled = false;
+    }
+
+    if let Ok((cam, cam_transform)) = small_view.get_single() {
+        let origin = cam.logical_viewport_rect().map_or(Vec2::ZERO, |rect| rect.min);
+        for drag in drags {
+            let Ok(ring) = rings.get(drag.target) else {
+                continue;
+            };
+            let Some(center) = cam.world_to_viewport(cam_transform, ring.translation()) else {
+                continue;
+            };
+            let current = drag.pointer_location.position - origin - center;
+            let previous = current - drag.delta;
+            if current == Vec2::ZERO || previous == Vec2::ZERO {
+                continue;
+            }
+            // Ring follows pointer, mirrored when looking from below.
+            let angle = previous.angle_between(current);
+            let sign = if orbit_camera.target_beta.sin() < 0.0 { -1.0 } else { 1.0 };
+            orbit_camera.target_alpha += sign * angle;
+        }
+    }
+
+    if *holding && released {
+        *holding = false;
+        orbit_camera.enabled = true;
+    }
+}
+
+/// Clicking compass ring faces the clicked point.
+pub(crate) fn compass_click(
+    mut drag_ends: EventReader<Pointer<DragEnd>>,
+    mut clicks: EventReader<Pointer<Click>>,
+    compass: Res<ViewcubeCompass>,
+    rings: Query<&GlobalTransform, With<CompassRing>>,
+    mut camera: Query<&mut PanOrbitCamera, With<crate::ViewcubeBinding>>,
+) {
+    // Releasing a drag also clicks, count all so no drag end is left for a later click.
+    let dragged = drag_ends.read().filter(|end|
+        rings.contains(end.target) && end.distance.length() > CLICK_DISTANCE
+    ).count() > 0;
+    for click in clicks.read() {
+        let (Ok(ring), Some(position)) = (rings.get(click.target), click.hit.position) else {
+            continue;
+        };
+        let Ok(mut orbit_camera) = camera.get_single_mut() else {
+            continue;
+        };
+        if dragged {
+            continue;
+        }
+        let local = ring.affine().inverse().transform_point3(position);
+        let heading = compass.rotation().mul_vec3(Vec3::new(local.x, 0.0, local.z));
+        if heading == Vec3::ZERO {
+            continue;
+        }
+        let current = orbit_camera.target_alpha;
+        orbit_camera.target_alpha = nearest_angle(current, heading_alpha(heading));
+    }
+}
@@ -0,0 +1,153 @@
+use bevy::{
+    prelude::*,
+    render::view::RenderLayers,
+    math::primitives::Sphere,
+    scene::SceneInstance,
+};
+use bevy_mod_picking::prelude::*;
+
+use crate::generate_viewcube_face;
+
+use super::{
+    ViewcubeHit,
+    ViewcubePart,
+    ViewcubeTarget,
+};
+
+/// Mesh of custom viewcube, snaps camera to `target` when clicked.
+#[derive(Clone)]
+pub struct CustomViewcubePart {
+    pub mesh: Mesh,
+    pub color: Color,
+    /// Placement relative to viewcube center.
+    pub transform: Transform,
+    pub target: ViewcubeTarget,
+}
+
+/// glTF scene used as viewcube.
+#[derive(Clone)]
+pub struct CustomViewcubeScene {
+    /// Asset path of scene, e.g. `"ship.glb#Scene0"`.
+    pub path: String,
+    /// Placement relative to viewcube center.
+    pub transform: Transform,
+    /// Node name with the target it snaps camera to.
+    /// Meshes belong to the nearest named ancestor found in the mapping.
+    pub parts: Vec<(String, ViewcubeTarget)>,
+}
+
+/// User supplied geometry replacing the built-in viewcube.
+/// Viewcube center is origin, built-in viewcube fits in a cube of size 1.
+#[derive(Clone, Default, Resource)]
+pub struct CustomViewcube {
+    pub parts: Vec<CustomViewcubePart>,
+    pub scene: Option<CustomViewcubeScene>,
+}
+
+#[derive(Component)]
+pub(crate) struct CustomSceneRoot;
+
+/// Scene root whose entities are already tagged.
+#[derive(Component)]
+pub(crate) struct PreparedScene;
+
+pub fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+    custom: Res<CustomViewcube>,
+) {
+    commands.spawn((
+        MaterialMeshBundle {
+            mesh: meshes.add(Sphere{radius: 0.01}),
+            material: materials.add(StandardMaterial::default()),
+            ..Default::default()
+        },
+        RenderLayers::layer(13),
+        super::ViewcubeCenter,
+    )).with_children(|builder| {
+        // Anchor of trident labels, custom geometry brings its own axes.
+        builder.spawn((
+            SpatialBundle::default(),
+            RenderLayers::layer(13),
+            super::ViewcubeTrident,
+        ));
+        for part in custom.parts.iter() {
+            let target = part.target;
+            builder.spawn(
+                generate_viewcube_face!(
+                    meshes, materials,
+                    part.mesh,
+                    part.color,
+                    part.transform,
+                    ViewcubeHit(target)
+                )
+            );
+        }
+        if let Some(scene) = &custom.scene {
+            builder.spawn((
+                SceneBundle {
+                    scene: asset_server.load(scene.path.clone()),
+                    transform: scene.transform,
+                    ..Default::default()
+                },
+                RenderLayers::layer(13),
+                CustomSceneRoot,
+            ));
+        }
+    });
+}
+
+/// Move spawned scene to viewcube layer and make mapped meshes pickable, once per scene when it is ready.
+#[allow(clippy::type_complexity)]
+pub(crate) fn prepare_scene(
+    mut commands: Commands,
+    custom: Res<CustomViewcube>,
+    scene_spawner: Res<SceneSpawner>,
+    roots: Query<(Entity, &SceneInstance), (With<CustomSceneRoot>, Without<PreparedScene>)>,
+    children: Query<&Children>,
+    parents: Query<&Parent>,
+    nodes: Query<(Has<RenderLayers>, Has<Handle<Mesh>>, Option<&Name>)>,
+) {
+    let Some(scene) = &custom.scene else {
+        return;
+    };
+    for (root, instance) in roots.iter() {
+        if !scene_spawner.instance_is_ready(**instance) {
+            continue;
+        }
+        commands.entity(root).insert(PreparedScene);
+        for entity in children.iter_descendants(root) {
+            let Ok((has_layers, has_mesh, _)) = nodes.get(entity) else {
+                continue;
+            };
+            if has_layers {
+                continue;
+            }
+            commands.entity(entity).insert(RenderLayers::layer(13));
+            if !has_mesh {
+                continue;
+            }
+            let target = std::iter::once(entity)
+                .chain(parents.iter_ancestors(entity))
+                .take_while(|ancestor| *ancestor != root)
+                .filter_map(|ancestor| nodes.get(ancestor).ok().and_then(|(_, _, name)| name))
+                .find_map(|name| scene.parts.iter()
+                    .find(|(part, _)| part.as_str() == name.as_str())
+                    .map(|(_, target)| *target)
+                );
+            if let Some(target) = target {
+                commands.entity(entity).insert((
+                    ViewcubePart,
+                    PickableBundle::default(),
+                    On::<Pointer<Click>>::commands_mut(move |_event, commands| {
+                        commands.add(move |world: &mut World| {
+                            world.send_event(ViewcubeHit(target));
+                        });
+                    }),
+                ));
+            }
+        }
+    }
+}
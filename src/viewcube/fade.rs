@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+
+use super::ViewcubePart;
+
+/// Fade the viewcube when pointer leaves the viewcube viewport.
+#[derive(Debug, Clone, Copy)]
+pub struct ViewcubeFade {
+    /// Opacity after fading out.
+    pub idle_opacity: f32,
+    /// Seconds without hovering before fading out.
+    pub delay: f32,
+    /// Seconds between opacity and idle_opacity.
+    pub duration: f32,
+}
+
+impl Default for ViewcubeFade {
+    fn default() -> Self {
+        Self {
+            idle_opacity: 0.3,
+            delay: 2.0,
+            duration: 0.5,
+        }
+    }
+}
+
+#[derive(Resource)]
+pub(crate) struct ViewcubeOpacity {
+    opacity: f32,
+    fade: Option<ViewcubeFade>,
+    current: f32,
+    idle: f32,
+    applied: Option<f32>,
+}
+
+impl ViewcubeOpacity {
+    pub fn new(opacity: f32, fade: Option<ViewcubeFade>) -> Self {
+        let opacity = opacity.clamp(0.0, 1.0);
+        Self {
+            opacity,
+            fade,
+            current: opacity,
+            idle: 0.0,
+            applied: None,
+        }
+    }
+
+    pub fn current(&self) -> f32 {
+        self.current
+    }
+
+    /// Step opacity toward target, returns current opacity.
+    fn step(&mut self, hovered: bool, delta: f32) -> f32 {
+        let Some(fade) = self.fade else {
+            self.current = self.opacity;
+            return self.current;
+        };
+        self.idle = if hovered { 0.0 } else { self.idle + delta };
+        let target = if self.idle >= fade.delay {
+            fade.idle_opacity.clamp(0.0, 1.0)
+        } else {
+            self.opacity
+        };
+        let range = (self.opacity - fade.idle_opacity).abs();
+        let max_step = if fade.duration > 0.0 {
+            range / fade.duration * delta
+        } else {
+            f32::INFINITY
+        };
+        self.current += (target - self.current).clamp(-max_step, max_step);
+        self.current
+    }
+}
+
+pub(crate) fn fade_viewcube(
+    time: Res<Time>,
+    windows: Query<&Window>,
+    camera: Query<&Camera, With<crate::SmallView>>,
+    mut opacity: ResMut<ViewcubeOpacity>,
+    parts: Query<&Handle<StandardMaterial>, With<ViewcubePart>>,
+    added: Query<(), Added<ViewcubePart>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let hovered = match (windows.get_single(), camera.get_single()) {
+        (Ok(window), Ok(camera)) => is_hovered(window, camera),
+        _ => false,
+    };
+    let alpha = opacity.step(hovered, time.delta_seconds());
+    if opacity.applied == Some(alpha) && added.is_empty() {
+        return;
+    }
+    opacity.applied = Some(alpha);
+
+    for handle in parts.iter() {
+        let Some(material) = materials.get_mut(handle) else {
+            continue;
+        };
+        material.base_color.set_a(alpha);
+        // Blended parts are sorted by translation, each part is placed at its own center.
+        material.alpha_mode = if alpha < 1.0 {
+            AlphaMode::Blend
+        } else {
+            AlphaMode::Opaque
+        };
+    }
+}
+
+fn is_hovered(window: &Window, camera: &Camera) -> bool {
+    let (Some(cursor), Some(viewport)) = (window.physical_cursor_position(), &camera.viewport) else {
+        return false;
+    };
+    let min = viewport.physical_position.as_vec2();
+    let max = min + viewport.physical_size.as_vec2();
+    cursor.cmpge(min).all() && cursor.cmplt(max).all()
+}
@@ -0,0 +1,124 @@
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+    render::mesh::VertexAttributeValues,
+};
+
+use super::{
+    fade::ViewcubeOpacity,
+    ViewcubePart,
+};
+
+/// Outline drawn along edges and face borders of viewcube.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct ViewcubeOutline {
+    pub color: Color,
+    /// Line width in pixels.
+    pub width: f32,
+}
+
+impl Default for ViewcubeOutline {
+    fn default() -> Self {
+        Self {
+            color: Color::BLACK,
+            width: 2.0,
+        }
+    }
+}
+
+/// Gizmos rendered on viewcube layer.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub(crate) struct ViewcubeGizmos;
+
+impl ViewcubeGizmos {
+    pub fn config(width: f32) -> GizmoConfig {
+        GizmoConfig {
+            line_width: width,
+            // Slightly in front of faces to avoid z-fighting.
+            depth_bias: -0.01,
+            render_layers: bevy::render::view::RenderLayers::layer(13),
+            ..Default::default()
+        }
+    }
+}
+
+/// Outline segments of a part in local space.
+#[derive(Component)]
+pub(crate) struct PartOutline(Vec<[Vec3; 2]>);
+
+/// Edges with larger angle between faces are outlined.
+const FEATURE_ANGLE: f32 = std::f32::consts::PI / 6.0;
+
+/// Ends of an edge and normals of faces sharing it.
+type Edge = ([Vec3; 2], Vec<Vec3>);
+
+impl PartOutline {
+    /// Collect boundary edges and feature edges of mesh.
+    fn from_mesh(mesh: &Mesh) -> Option<Self> {
+        let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            return None;
+        };
+        let positions = positions.iter().map(|p| Vec3::from(*p)).collect::<Vec<_>>();
+        let indices = match mesh.indices() {
+            Some(indices) => indices.iter().collect::<Vec<_>>(),
+            None => (0..positions.len()).collect(),
+        };
+
+        // Split vertices share position, key edges by position.
+        let key = |p: Vec3| (p * 1e4).round().as_ivec3().to_array();
+        let mut edges: HashMap<([i32; 3], [i32; 3]), Edge> = HashMap::new();
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [positions[triangle[0]], positions[triangle[1]], positions[triangle[2]]];
+            let normal = (b - a).cross(c - a).normalize_or_zero();
+            if normal == Vec3::ZERO {
+                continue;
+            }
+            for (p, q) in [(a, b), (b, c), (c, a)] {
+                let (kp, kq) = (key(p), key(q));
+                let id = if kp < kq { (kp, kq) } else { (kq, kp) };
+                edges.entry(id).or_insert_with(|| ([p, q], vec![])).1.push(normal);
+            }
+        }
+
+        let segments = edges.into_values().filter_map(|(segment, normals)| {
+            let outlined = match normals.as_slice() {
+                [n0, n1] => n0.angle_between(*n1) > FEATURE_ANGLE,
+                _ => true,
+            };
+            outlined.then_some(segment)
+        }).collect();
+        Some(Self(segments))
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn build_outline(
+    mut commands: Commands,
+    parts: Query<(Entity, &Handle<Mesh>), (With<ViewcubePart>, Without<PartOutline>)>,
+    meshes: Res<Assets<Mesh>>,
+) {
+    for (entity, handle) in parts.iter() {
+        if let Some(outline) = meshes.get(handle).and_then(PartOutline::from_mesh) {
+            commands.entity(entity).insert(outline);
+        }
+    }
+}
+
+pub(crate) fn draw_outline(
+    mut gizmos: Gizmos<ViewcubeGizmos>,
+    outline: Res<ViewcubeOutline>,
+    opacity: Res<ViewcubeOpacity>,
+    parts: Query<(&GlobalTransform, &PartOutline)>,
+) {
+    let mut color = outline.color;
+    color.set_a(color.a() * opacity.current());
+    for (transform, part) in parts.iter() {
+        for [start, end] in part.0.iter() {
+            gizmos.line(
+                transform.transform_point(*start),
+                transform.transform_point(*end),
+                color,
+            );
+        }
+    }
+}
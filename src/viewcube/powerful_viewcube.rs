@@ -27,12 +27,24 @@ struct ViewcubeEdge(pub CubePart);
 #[derive(Clone, Copy)]
 struct ViewcubeCorner(pub CubePart);
 
-impl From<ViewcubeEdge> for Mesh {
-    fn from(value: ViewcubeEdge) -> Self {
-        let mut width = 0.6f32 / 2.0;
-        let mut height = 0.1 * 2f32.sqrt() / 2.0;
+const EDGES: [CubePart; 12] = [
+    CubePart::FrontTop, CubePart::FrontBottom, CubePart::BackTop, CubePart::BackBottom,
+    CubePart::LeftTop, CubePart::LeftBottom, CubePart::RightTop, CubePart::RightBottom,
+    CubePart::FrontLeft, CubePart::FrontRight, CubePart::BackLeft, CubePart::BackRight,
+];
 
-        let (q, swap) = match value.0 {
+const CORNERS: [CubePart; 8] = [
+    CubePart::FrontLeftTop, CubePart::FrontLeftBottom,
+    CubePart::FrontRightTop, CubePart::FrontRightBottom,
+    CubePart::BackLeftTop, CubePart::BackLeftBottom,
+    CubePart::BackRightTop, CubePart::BackRightBottom,
+];
+
+const DIST_EDGE: f32 = 0.4 * std::f32::consts::SQRT_2 - 0.1 * std::f32::consts::SQRT_2 / 2.0;
+
+impl ViewcubeEdge {
+    fn rotation(&self) -> (Quat, bool) {
+        match self.0 {
             CubePart::FrontTop => (Quat::from_rotation_x(PI_4), false),
             CubePart::FrontBottom => (Quat::from_rotation_x(PI_4_3), false),
             CubePart::BackTop => (Quat::from_rotation_x(-PI_4), false),
@@ -58,17 +70,29 @@ impl From<ViewcubeEdge> for Mesh {
                 false
             ),
             _ => panic!(),
-        };
+        }
+    }
 
+    /// Center of edge, used as translation so blended parts sort correctly.
+    fn center(&self) -> Vec3 {
+        self.rotation().0.mul_vec3(Vec3::new(0.0, DIST_EDGE, 0.0))
+    }
+}
+
+impl From<ViewcubeEdge> for Mesh {
+    fn from(value: ViewcubeEdge) -> Self {
+        let mut width = 0.6f32 / 2.0;
+        let mut height = 0.1 * 2f32.sqrt() / 2.0;
+
+        let (q, swap) = value.rotation();
         if swap {
             (width, height) = (height, width);
         }
-        let dist_edge: f32 = (2.0 * 0.4f32.powi(2)).sqrt() - 0.1 * 2f32.sqrt() / 2.0;
-        let positions = vec![
-            Vec3::new( width, dist_edge,  height),
-            Vec3::new( width, dist_edge, -height),
-            Vec3::new(-width, dist_edge, -height),
-            Vec3::new(-width, dist_edge,  height),
+        let positions = [
+            Vec3::new( width, 0.0,  height),
+            Vec3::new( width, 0.0, -height),
+            Vec3::new(-width, 0.0, -height),
+            Vec3::new(-width, 0.0,  height),
         ].iter().map(|v| q.mul_vec3(*v)).collect::<Vec<_>>();
         
         let uvs = vec![[0f32,0.0],[0.0,1.0],[1.0,1.0],[1.0,0.0]];
@@ -86,67 +110,47 @@ impl From<ViewcubeEdge> for Mesh {
     }
 }
 
+impl ViewcubeCorner {
+    /// Octant of corner.
+    fn sign(&self) -> Vec3 {
+        match self.0 {
+            CubePart::FrontLeftTop => Vec3::new(-1.0, 1.0, 1.0),
+            CubePart::FrontLeftBottom => Vec3::new(-1.0, -1.0, 1.0),
+            CubePart::FrontRightTop => Vec3::new(1.0, 1.0, 1.0),
+            CubePart::FrontRightBottom => Vec3::new(1.0, -1.0, 1.0),
+            CubePart::BackLeftTop => Vec3::new(-1.0, 1.0, -1.0),
+            CubePart::BackLeftBottom => Vec3::new(-1.0, -1.0, -1.0),
+            CubePart::BackRightTop => Vec3::new(1.0, 1.0, -1.0),
+            CubePart::BackRightBottom => Vec3::new(1.0, -1.0, -1.0),
+            _ => panic!(),
+        }
+    }
+
+    /// Centroid of corner, used as translation so blended parts sort correctly.
+    fn center(&self) -> Vec3 {
+        self.sign() / 3.0
+    }
+}
+
 impl From<ViewcubeCorner> for Mesh {
     fn from(value: ViewcubeCorner) -> Self {
-        let edge =0.1 * 2f32.sqrt();
-        let a = edge / 2.0;
-        let b = a * 3f32.sqrt();
-
-        let d = 1.0 / 3.0f32;
+        let sign = value.sign();
+        let center = value.center();
 
-        let (q, c) = match value.0 {
-            CubePart::FrontLeftTop => (
-                Quat::from_euler(
-                EulerRot::YXZ, -PI_4, PI_4, 0.0),
-                Vec3::new(-d, d, d)
-            ),
-            CubePart::FrontLeftBottom => (
-                Quat::from_euler(
-                EulerRot::YXZ, PI_4_3, -PI_4_3, 0.0),
-                Vec3::new(-d, -d, d)
-            ),
-            CubePart::FrontRightTop => (
-                Quat::from_euler(
-                EulerRot::YXZ, PI_4, PI_4, 0.0),
-                Vec3::new(d, d, d)
-            ),
-            CubePart::FrontRightBottom => (
-                Quat::from_euler(
-                EulerRot::YXZ, -PI_4_3, -PI_4_3, 0.0),
-                Vec3::new(d, -d, d)
-            ),
-            CubePart::BackLeftTop => (
-                Quat::from_euler(
-                EulerRot::YXZ, -PI_4_3, PI_4, 0.0),
-                Vec3::new(-d, d, -d)
-            ),
-            CubePart::BackLeftBottom => (
-                Quat::from_euler(
-                EulerRot::YXZ, PI_4, -PI_4_3, 0.0),
-                Vec3::new(-d, -d, -d)
-            ),
-            CubePart::BackRightTop => (
-                Quat::from_euler(
-                EulerRot::YXZ, PI_4_3, PI_4, 0.0),
-                Vec3::new(d, d, -d)
-            ),
-            CubePart::BackRightBottom => (
-                Quat::from_euler(
-                EulerRot::YXZ, -PI_4, -PI_4_3, 0.0),
-                Vec3::new(d, -d, -d)
-            ),
-            _ => panic!(),
-        };
-        
-        let positions = vec![
-            Vec3::new(0.0, 0.0, -b /3.0 * 2.0),
-            Vec3::new( -a, 0.0, b / 3.0),
-            Vec3::new(  a, 0.0, b / 3.0),
-        ].iter().map(|v| q.mul_vec3(*v) + c).collect::<Vec<_>>();
+        // Chamfer cuts 0.1 off each axis at the corner of a cube of size 0.8.
+        let mut positions = [0, 1, 2].map(|axis| {
+            let mut vertex = Vec3::splat(0.3);
+            vertex[axis] = 0.4;
+            vertex * sign - center
+        }).to_vec();
+        // Mirroring an odd number of axes flips winding.
+        if sign.x * sign.y * sign.z < 0.0 {
+            positions.swap(1, 2);
+        }
 
         let uvs = vec![[0f32,0.0],[0.0,1.0],[1.0,1.0]];
         let indices = vec![0u32, 1, 2];
-        let normal = q.mul_vec3(Vec3::Y);
+        let normal = sign.normalize();
         let mut normals = vec![];
         normals.resize_with(3, || normal);
 
@@ -159,7 +163,6 @@ impl From<ViewcubeCorner> for Mesh {
     }
 }
 
-
 pub fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -184,187 +187,106 @@ pub fn setup(
                 ..Default::default()
             },
             RenderLayers::layer(13),
+            super::ViewcubeTrident,
         ));
         generate_viewcube_simple_face(0.6f32, builder, &mut meshes, &mut materials);
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::FrontTop),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::FrontTop)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::FrontBottom),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::FrontBottom)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::BackTop),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::BackTop)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::BackBottom),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::BackBottom)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::LeftTop),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::LeftTop)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::LeftBottom),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::LeftBottom)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::RightTop),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::RightTop)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::RightBottom),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::RightBottom)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::FrontLeft),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::FrontLeft)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::FrontRight),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::FrontRight)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::BackLeft),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::BackLeft)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeEdge(CubePart::BackRight),
-                Color::PINK,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::BackRight)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeCorner(CubePart::FrontLeftTop),
-                Color::VIOLET,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::FrontLeftTop)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeCorner(CubePart::FrontLeftBottom),
-                Color::VIOLET,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::FrontLeftBottom)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeCorner(CubePart::FrontRightTop),
-                Color::VIOLET,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::FrontRightTop)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeCorner(CubePart::FrontRightBottom),
-                Color::VIOLET,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::FrontRightBottom)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeCorner(CubePart::BackLeftTop),
-                Color::VIOLET,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::BackLeftTop)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeCorner(CubePart::BackLeftBottom),
-                Color::VIOLET,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::BackLeftBottom)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeCorner(CubePart::BackRightTop),
-                Color::VIOLET,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::BackRightTop)
-            )
-        );
-        builder.spawn(
-            generate_viewcube_face!(
-                meshes, materials,
-                ViewcubeCorner(CubePart::BackRightBottom),
-                Color::VIOLET,
-                Transform::IDENTITY,
-                ViewcubeHit(CubePart::BackRightBottom)
-            )
-        );
+        for part in EDGES {
+            let edge = ViewcubeEdge(part);
+            builder.spawn(
+                generate_viewcube_face!(
+                    meshes, materials,
+                    edge,
+                    Color::PINK,
+                    Transform::from_translation(edge.center()),
+                    ViewcubeHit(part.into())
+                )
+            );
+        }
+        for part in CORNERS {
+            let corner = ViewcubeCorner(part);
+            builder.spawn(
+                generate_viewcube_face!(
+                    meshes, materials,
+                    corner,
+                    Color::VIOLET,
+                    Transform::from_translation(corner.center()),
+                    ViewcubeHit(part.into())
+                )
+            );
+        }
     });
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::mesh_validation::{assert_valid, triangles};
+
+    /// All 26 parts of powerful viewcube placed as spawned.
+    fn parts() -> Vec<(Mesh, Transform)> {
+        let plane = Mesh::from(Plane3d::default().mesh().size(0.6, 0.6));
+        let faces = super::super::simple_viewcube::simple_faces()
+            .map(|(_, _, transform)| (plane.clone(), transform));
+        let edges = EDGES.map(|part| {
+            let edge = ViewcubeEdge(part);
+            (Mesh::from(edge), Transform::from_translation(edge.center()))
+        });
+        let corners = CORNERS.map(|part| {
+            let corner = ViewcubeCorner(part);
+            (Mesh::from(corner), Transform::from_translation(corner.center()))
+        });
+        faces.into_iter().chain(edges).chain(corners).collect()
+    }
+
+    #[test]
+    fn edges_and_corners_are_valid() {
+        for part in EDGES {
+            assert_valid(&Mesh::from(ViewcubeEdge(part)));
+        }
+        for part in CORNERS {
+            assert_valid(&Mesh::from(ViewcubeCorner(part)));
+        }
+    }
+
+    #[test]
+    fn parts_face_outward() {
+        for (mesh, transform) in parts() {
+            for ([a, b, c], _) in triangles(&mesh, transform) {
+                let face = (b - a).cross(c - a);
+                assert!(face.dot(a + b + c) > 0.0, "triangle {:?} faces inward", [a, b, c]);
+            }
+        }
+    }
+
+    #[test]
+    fn parts_tile_chamfered_cube() {
+        let parts = parts();
+        assert_eq!(parts.len(), 26);
+
+        // Cube of size 0.8 with chamfer 0.1 on every edge.
+        let chamfer = 0.1 * std::f32::consts::SQRT_2;
+        let expected = 6.0 * 0.6 * 0.6
+            + 12.0 * 0.6 * chamfer
+            + 8.0 * 3f32.sqrt() / 4.0 * chamfer * chamfer;
+        let mut area = 0.0;
+
+        // Closed surface without overlaps uses every edge once in each direction.
+        let key = |v: Vec3| (v * 1e4).round().as_ivec3();
+        let mut edges: HashMap<(IVec3, IVec3), usize> = HashMap::new();
+        for (mesh, transform) in parts.iter() {
+            for ([a, b, c], _) in triangles(mesh, *transform) {
+                area += (b - a).cross(c - a).length() / 2.0;
+                for (from, to) in [(a, b), (b, c), (c, a)] {
+                    *edges.entry((key(from), key(to))).or_default() += 1;
+                }
+            }
+        }
+        assert!((area - expected).abs() < 1e-4, "area {area}, expected {expected}");
+        for ((from, to), count) in edges.iter() {
+            assert_eq!(*count, 1, "edge {from} {to} used {count} times");
+            assert_eq!(edges.get(&(*to, *from)), Some(&1), "edge {from} {to} is open");
+        }
+    }
+}
@@ -38,11 +38,34 @@ pub fn setup(
                 ..Default::default()
             },
             RenderLayers::layer(13),
+            super::ViewcubeTrident,
         ));
         generate_viewcube_simple_face(0.8f32, builder, &mut meshes, &mut materials);
     });
 }
 
+/// Part, color and placement of the six faces.
+pub(crate) fn simple_faces() -> [(CubePart, Color, Transform); 6] {
+    let half = 0.4f32;
+    let face = |rotation: Quat, translation: Vec3| Transform::from_matrix(
+        Mat4::from_rotation_translation(rotation, translation)
+    );
+    [
+        // Right (+X)
+        (CubePart::Right, Color::RED, face(Quat::from_rotation_z(-crate::PI / 2.0), Vec3::new(half, 0.0, 0.0))),
+        // Left (-X)
+        (CubePart::Left, Color::RED, face(Quat::from_rotation_z(crate::PI / 2.0), Vec3::new(-half, 0.0, 0.0))),
+        // Top (+Y)
+        (CubePart::Top, Color::GREEN, face(Quat::from_rotation_x(0.0), Vec3::new(0.0, half, 0.0))),
+        // Bottom (-Y)
+        (CubePart::Bottom, Color::GREEN, face(Quat::from_rotation_x(crate::PI), Vec3::new(0.0, -half, 0.0))),
+        // Front (+Z)
+        (CubePart::Front, Color::BLUE, face(Quat::from_rotation_x(crate::PI / 2.0), Vec3::new(0.0, 0.0, half))),
+        // Back (-Z)
+        (CubePart::Back, Color::BLUE, face(Quat::from_rotation_x(-crate::PI / 2.0), Vec3::new(0.0, 0.0, -half))),
+    ]
+}
+
 pub fn generate_viewcube_simple_face(
     size: f32,
     builder: &mut ChildBuilder,
@@ -50,89 +73,15 @@ pub fn generate_viewcube_simple_face(
     materials: &mut Assets<StandardMaterial>,
 ) {
     let plane = Plane3d::default().mesh().size(size, size);
-    let half = 0.4f32;
-    // Right (+X)
-    builder.spawn(
-        generate_viewcube_face!(
-            meshes, materials,
-            plane,
-            Color::RED,
-            Transform::from_matrix(
-                Mat4::from_rotation_translation(
-                    Quat::from_rotation_z(-crate::PI / 2.0),
-                    Vec3::new(half, 0.0, 0.0),
-                )),
-            ViewcubeHit(CubePart::Right)
-        )
-    );
-    // Left (-X)
-    builder.spawn(
-        generate_viewcube_face!(
-            meshes, materials,
-            plane,
-            Color::RED,
-            Transform::from_matrix(
-                Mat4::from_rotation_translation(
-                    Quat::from_rotation_z(crate::PI / 2.0),
-                    Vec3::new(-half, 0.0, 0.0),
-                )),
-            ViewcubeHit(CubePart::Left)
-        )
-    );
-    // Top (+Y)
-    builder.spawn(
-        generate_viewcube_face!(
-            meshes, materials,
-            plane,
-            Color::GREEN,
-            Transform::from_matrix(
-                Mat4::from_rotation_translation(
-                    Quat::from_rotation_x(0.0),
-                    Vec3::new(0.0, half,0.0),
-                )),
-            ViewcubeHit(CubePart::Top)
-        )
-    );
-    // Bottom (-Y)
-    builder.spawn(
-        generate_viewcube_face!(
-            meshes, materials,
-            plane,
-            Color::GREEN,
-            Transform::from_matrix(
-                Mat4::from_rotation_translation(
-                    Quat::from_rotation_x(crate::PI),
-                    Vec3::new(0.0, -half,0.0),
-                )),
-            ViewcubeHit(CubePart::Bottom)
-        )
-    );
-    // Front (+Z)
-    builder.spawn(
-        generate_viewcube_face!(
-            meshes, materials,
-            plane,
-            Color::BLUE,
-            Transform::from_matrix(
-                Mat4::from_rotation_translation(
-                    Quat::from_rotation_x(crate::PI / 2.0),
-                    Vec3::new(0.0, 0.0, half),
-                )),
-            ViewcubeHit(CubePart::Front)
-        )
-    );
-    // Back (-Z)
-    builder.spawn(
-        generate_viewcube_face!(
-            meshes, materials,
-            plane,
-            Color::BLUE,
-            Transform::from_matrix(
-                Mat4::from_rotation_translation(
-                    Quat::from_rotation_x(-crate::PI / 2.0),
-                    Vec3::new(0.0, 0.0, -half),
-                )),
-            ViewcubeHit(CubePart::Back)
-        )
-    );
-}
\ No newline at end of file
+    for (part, color, transform) in simple_faces() {
+        builder.spawn(
+            generate_viewcube_face!(
+                meshes, materials,
+                plane,
+                color,
+                transform,
+                ViewcubeHit(part.into())
+            )
+        );
+    }
+}